@@ -0,0 +1,348 @@
+use crate::{Balance, BalanceError, MinimalSteps, Offset, PathSlice};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// A sequence of at most `N` movements, stored inline: the allocation-free counterpart of `Path`.
+///
+/// An `ArrayPath` has a fixed capacity `N` and a variable length, and needs no allocator.
+/// It provides the same API as `Path` (`to_vector`, `normalized`, `reversed`, `each`,
+/// `each_zip`...) and dereferences to a `[Balance]` slice of its movements.
+///
+/// The transformations never make a path longer, so they return an `ArrayPath` of the same
+/// capacity. Adding a movement to a full `ArrayPath` panics with [ArrayPath::push], and
+/// fails with [ArrayPath::try_push].
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{ArrayPath, Balance};
+///
+/// let mut path = ArrayPath::<8>::new();
+/// path.push(Balance::Top);
+/// path.push(Balance::Right);
+/// path.push(Balance::Top);
+/// assert_eq!(path.len(), 3);
+/// assert_eq!(path.to_vector(), (1, -2));
+/// assert_eq!(path.normalized().as_slice(), &[Balance::TopRight, Balance::Top]);
+/// assert_eq!(path.reversed()[0], Balance::Top);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ArrayPath<const N: usize> {
+    raw: [Balance; N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayPath<N> {
+    /// Creates an empty `ArrayPath`.
+    pub const fn new() -> Self {
+        Self {
+            raw: [Balance::Center; N],
+            len: 0,
+        }
+    }
+
+    /// Creates an `ArrayPath` holding a copy of `movements`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CapacityExceeded] if there are more than `N` movements.
+    pub const fn from_slice(movements: &[Balance]) -> Result<Self, BalanceError> {
+        if movements.len() > N {
+            return Err(BalanceError::CapacityExceeded(N));
+        }
+        let mut path = Self::new();
+        while path.len < movements.len() {
+            path.raw[path.len] = movements[path.len];
+            path.len += 1;
+        }
+        Ok(path)
+    }
+
+    /// Returns the maximal number of movements, `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of movements in the `ArrayPath`.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the `ArrayPath` is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks whether the `ArrayPath` holds `N` movements.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the movements of the `ArrayPath`.
+    pub fn as_slice(&self) -> &[Balance] {
+        &self.raw[..self.len]
+    }
+
+    /// Returns the movements of the `ArrayPath`, mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [Balance] {
+        &mut self.raw[..self.len]
+    }
+
+    /// Returns a [PathSlice] view of the movements.
+    pub fn as_path_slice(&self) -> PathSlice<'_> {
+        PathSlice::new(self.as_slice())
+    }
+
+    /// Retrieves the `Balance` at the specified index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Balance> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns an iterator over references to the movements.
+    pub fn iter(&self) -> core::slice::Iter<'_, Balance> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the movements.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, Balance> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Appends a movement to the end of the `ArrayPath`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CapacityExceeded] if the `ArrayPath` is full.
+    pub const fn try_push(&mut self, movement: Balance) -> Result<(), BalanceError> {
+        if self.len == N {
+            return Err(BalanceError::CapacityExceeded(N));
+        }
+        self.raw[self.len] = movement;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends a movement to the end of the `ArrayPath`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `ArrayPath` is full. Use [ArrayPath::try_push] for a non-panicking push.
+    pub const fn push(&mut self, movement: Balance) {
+        if self.try_push(movement).is_err() {
+            panic!("ArrayPath capacity exceeded");
+        }
+    }
+
+    /// Removes the last movement from the `ArrayPath`, if any, and returns it.
+    pub const fn pop(&mut self) -> Option<Balance> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let movement = self.raw[self.len];
+        self.raw[self.len] = Balance::Center;
+        Some(movement)
+    }
+
+    /// Clears all movements from the `ArrayPath`, leaving it empty.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Collects movements which are known to fit in the capacity.
+    fn collect_within(movements: impl Iterator<Item = Balance>) -> Self {
+        let mut path = Self::new();
+        for movement in movements {
+            path.push(movement);
+        }
+        path
+    }
+
+    /// Returns the cumulative movement of the `ArrayPath` (see `Path::to_vector`).
+    pub fn to_vector(&self) -> (i8, i8) {
+        self.as_path_slice().to_vector()
+    }
+
+    /// Converts a vector `(x, y)` into an `ArrayPath`, diagonal steps first (see `Path::from_vector`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CapacityExceeded] if the path needs more than `N` movements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ArrayPath, BalanceError};
+    ///
+    /// let path = ArrayPath::<4>::try_from_vector(3, -1).unwrap();
+    /// assert_eq!(path.len(), 3);
+    /// assert_eq!(
+    ///     ArrayPath::<4>::try_from_vector(5, 0),
+    ///     Err(BalanceError::CapacityExceeded(4))
+    /// );
+    /// ```
+    pub fn try_from_vector(x: i8, y: i8) -> Result<Self, BalanceError> {
+        let steps = MinimalSteps::from_offset(Offset::new(x, y));
+        if steps.len() > N {
+            return Err(BalanceError::CapacityExceeded(N));
+        }
+        Ok(Self::collect_within(steps))
+    }
+
+    /// Returns the normalized `ArrayPath` (see `Path::normalized`).
+    pub fn normalized(&self) -> Self {
+        Self::collect_within(self.as_path_slice().normalized())
+    }
+
+    /// Returns the `ArrayPath` with its movements in reverse order (see `Path::reversed`).
+    pub fn reversed(&self) -> Self {
+        Self::collect_within(self.as_path_slice().reversed())
+    }
+
+    /// Returns the `ArrayPath` of the results of `f` on each movement (see `Path::each`).
+    pub fn each(&self, f: impl Fn(Balance) -> Balance) -> Self {
+        Self::collect_within(self.as_path_slice().each(f))
+    }
+
+    /// Returns the `ArrayPath` of the results of `f` on each movement and `other`
+    /// (see `Path::each_with`).
+    pub fn each_with(&self, f: impl Fn(Balance, Balance) -> Balance, other: Balance) -> Self {
+        Self::collect_within(self.as_path_slice().each_with(f, other))
+    }
+
+    /// Returns the `ArrayPath` of the results of `f` on the pairs of movements of this
+    /// `ArrayPath` and `other` (see `Path::each_zip`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ArrayPath, Balance};
+    /// use core::ops::Add;
+    ///
+    /// let a = ArrayPath::<4>::from_slice(&[Balance::Top, Balance::Right]).unwrap();
+    /// let b = ArrayPath::<4>::from_slice(&[Balance::Bottom, Balance::Top]).unwrap();
+    /// assert_eq!(a.each_zip(Balance::add, &b).as_slice(), &[Balance::Center, Balance::TopRight]);
+    /// ```
+    pub fn each_zip(&self, f: impl Fn(Balance, Balance) -> Balance, other: &Self) -> Self {
+        Self::collect_within(self.as_path_slice().each_zip(f, other.as_path_slice()))
+    }
+}
+
+impl<const N: usize> Default for ArrayPath<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayPath<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for ArrayPath<N> {}
+
+impl<const N: usize> Hash for ArrayPath<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<const N: usize> Deref for ArrayPath<N> {
+    type Target = [Balance];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayPath<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<const N: usize> TryFrom<&[Balance]> for ArrayPath<N> {
+    type Error = BalanceError;
+
+    fn try_from(movements: &[Balance]) -> Result<Self, Self::Error> {
+        Self::from_slice(movements)
+    }
+}
+
+impl<const N: usize> FromIterator<Balance> for ArrayPath<N> {
+    /// Collects movements into an `ArrayPath`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `N` movements.
+    fn from_iter<T: IntoIterator<Item = Balance>>(iter: T) -> Self {
+        Self::collect_within(iter.into_iter())
+    }
+}
+
+impl<const N: usize> Extend<Balance> for ArrayPath<N> {
+    /// Appends movements to the `ArrayPath`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is exceeded.
+    fn extend<T: IntoIterator<Item = Balance>>(&mut self, iter: T) {
+        for movement in iter {
+            self.push(movement);
+        }
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a ArrayPath<N> {
+    type Item = &'a Balance;
+    type IntoIter = core::slice::Iter<'a, Balance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize> From<&'a ArrayPath<N>> for PathSlice<'a> {
+    fn from(path: &'a ArrayPath<N>) -> Self {
+        path.as_path_slice()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{ArrayPath, Balance, Path};
+
+    #[test]
+    fn test_matches_path() {
+        let movements = [
+            Balance::Top,
+            Balance::Left,
+            Balance::BottomLeft,
+            Balance::Center,
+            Balance::Left,
+            Balance::Bottom,
+        ];
+        let path = Path::new(movements.to_vec());
+        let array = ArrayPath::<8>::from_slice(&movements).unwrap();
+        assert_eq!(array.to_vector(), path.to_vector());
+        assert_eq!(array.normalized()[..], path.normalized()[..]);
+        assert_eq!(array.reversed()[..], path.reversed()[..]);
+        assert_eq!(
+            array.each(Balance::rotate_left)[..],
+            path.each(Balance::rotate_left)[..]
+        );
+        assert_eq!(
+            array.each_zip(core::ops::Add::add, &array.reversed())[..],
+            path.each_zip(core::ops::Add::add, &path.reversed())[..]
+        );
+
+        let mut full = array;
+        full.extend([Balance::Top, Balance::Top]);
+        assert!(full.is_full());
+        assert!(full.try_push(Balance::Top).is_err());
+        assert_eq!(full.pop(), Some(Balance::Top));
+        assert_eq!(full.pop(), Some(Balance::Top));
+        assert_eq!(full, array);
+    }
+}
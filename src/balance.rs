@@ -29,6 +29,7 @@
 /// assert_eq!(center.to_vector(), (0, 0));
 /// ```
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Balance {
     /// `TopLeft`: The position at `(-1, -1)`
     TopLeft,
@@ -51,6 +52,29 @@ pub enum Balance {
 }
 
 impl Balance {
+    /// All the `Balance` variants, in the order of [Balance::to_value] (and of [Balance::index]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::ALL[0], Balance::TopLeft);
+    /// assert_eq!(Balance::ALL[4], Balance::Center);
+    /// assert!(Balance::ALL.iter().enumerate().all(|(i, b)| b.index() == i));
+    /// ```
+    pub const ALL: [Balance; 9] = [
+        Balance::TopLeft,
+        Balance::Top,
+        Balance::TopRight,
+        Balance::Left,
+        Balance::Center,
+        Balance::Right,
+        Balance::BottomLeft,
+        Balance::Bottom,
+        Balance::BottomRight,
+    ];
+
     /// Returns the x-coordinate of the current `Balance` position in the 3x3 grid.
     ///
     /// # Returns
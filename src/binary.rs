@@ -0,0 +1,344 @@
+use crate::{Balance, UnaryOp};
+
+/// Builds a `BinaryOp` by evaluating a (const) expression on each pair of `Balance`s.
+macro_rules! tabulate {
+    ($a:ident, $b:ident => $e:expr) => {
+        tabulate!(@rows $a, $b => $e; TopLeft, Top, TopRight, Left, Center, Right, BottomLeft, Bottom, BottomRight)
+    };
+    (@rows $a:ident, $b:ident => $e:expr; $($variant:ident),*) => {
+        BinaryOp([$({
+            let $a = Balance::$variant;
+            tabulate!(@row $b => $e; TopLeft, Top, TopRight, Left, Center, Right, BottomLeft, Bottom, BottomRight)
+        }),*])
+    };
+    (@row $b:ident => $e:expr; $($variant:ident),*) => {
+        [$({
+            let $b = Balance::$variant;
+            $e
+        }),*]
+    };
+}
+
+/// Clamps the sum (or the difference) of two coordinates to `-1..=1`.
+const fn clamp(value: i8) -> i8 {
+    value.signum()
+}
+
+/// A binary operation on `Balance`, stored as its 9x9 table of results.
+///
+/// `table[a][b]` is the result for the arguments `a` and `b`, indexed in the order of
+/// [Balance::to_value] (from `TopLeft` to `BottomRight`). Unlike a function, a `BinaryOp`
+/// can be stored, compared and composed, and its algebraic properties can be checked
+/// exhaustively.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, BinaryOp};
+///
+/// let op = BinaryOp::from_fn(|a, b| a * b);
+/// assert_eq!(op, BinaryOp::MUL);
+/// assert!(op.is_commutative() && op.is_associative());
+/// assert_eq!(op.identity(), Some(Balance::BottomRight));
+/// assert_eq!(op.absorbing(), Some(Balance::Center));
+///
+/// assert!(!BinaryOp::ADD.is_associative());
+/// let min = BinaryOp::from_fn(|a, b| Balance::from_vector(a.x().min(b.x()), a.y().min(b.y())));
+/// let max = BinaryOp::from_fn(|a, b| Balance::from_vector(a.x().max(b.x()), a.y().max(b.y())));
+/// assert!(min.forms_lattice_with(&max));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct BinaryOp(pub [[Balance; 9]; 9]);
+
+impl BinaryOp {
+    /// See [Balance::add](core::ops::Add::add).
+    pub const ADD: Self =
+        tabulate!(a, b => Balance::from_vector(clamp(a.x() + b.x()), clamp(a.y() + b.y())));
+    /// See [Balance::sub](core::ops::Sub::sub).
+    pub const SUB: Self =
+        tabulate!(a, b => Balance::from_vector(clamp(a.x() - b.x()), clamp(a.y() - b.y())));
+    /// See [Balance::mul](core::ops::Mul::mul).
+    pub const MUL: Self = tabulate!(a, b => Balance::from_vector(a.x() * b.x(), a.y() * b.y()));
+    /// See [Balance::bitand](core::ops::BitAnd::bitand).
+    #[cfg(feature = "ternary")]
+    pub const AND: Self = tabulate!(a, b => Balance::from_vector(
+        if a.x() < b.x() { a.x() } else { b.x() },
+        if a.y() < b.y() { a.y() } else { b.y() },
+    ));
+    /// See [Balance::bitor](core::ops::BitOr::bitor).
+    #[cfg(feature = "ternary")]
+    pub const OR: Self = tabulate!(a, b => Balance::from_vector(
+        if a.x() > b.x() { a.x() } else { b.x() },
+        if a.y() > b.y() { a.y() } else { b.y() },
+    ));
+    /// See [Balance::bitxor](core::ops::BitXor::bitxor).
+    #[cfg(feature = "ternary")]
+    pub const XOR: Self =
+        tabulate!(a, b => Balance::from_vector(-(a.x() * b.x()), -(a.y() * b.y())));
+    /// See [Balance::k3_imply].
+    #[cfg(feature = "ternary")]
+    pub const K3_IMPLY: Self = tabulate!(a, b => a.k3_imply(b));
+    /// See [Balance::k3_equiv].
+    #[cfg(feature = "ternary")]
+    pub const K3_EQUIV: Self = tabulate!(a, b => a.k3_equiv(b));
+    /// See [Balance::ht_imply].
+    #[cfg(feature = "ternary")]
+    pub const HT_IMPLY: Self = tabulate!(a, b => a.ht_imply(b));
+
+    /// Creates a `BinaryOp` by tabulating the function `f` on the 81 pairs of `Balance` variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp};
+    ///
+    /// let op = BinaryOp::from_fn(|a, b| a + b);
+    /// assert_eq!(op, BinaryOp::ADD);
+    /// assert_eq!(op.apply(Balance::Top, Balance::Right), Balance::TopRight);
+    /// ```
+    pub fn from_fn(f: impl Fn(Balance, Balance) -> Balance) -> Self {
+        Self(Balance::ALL.map(|a| Balance::ALL.map(|b| f(a, b))))
+    }
+
+    /// Applies the operation on the pair `(a, b)`.
+    pub const fn apply(&self, a: Balance, b: Balance) -> Balance {
+        self.0[a.index()][b.index()]
+    }
+
+    /// Returns the `BinaryOp` with swapped arguments: `(a, b) -> self(b, a)`.
+    pub fn transposed(&self) -> Self {
+        Self::from_fn(|a, b| self.apply(b, a))
+    }
+
+    /// Returns the `BinaryOp` applying `op` on the results of this one: `(a, b) -> op(self(a, b))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{BinaryOp, UnaryOp};
+    ///
+    /// // The negation of a sum is the sum of the negations.
+    /// assert_eq!(
+    ///     BinaryOp::ADD.then(UnaryOp::NEG),
+    ///     BinaryOp::ADD.precompose(UnaryOp::NEG, UnaryOp::NEG)
+    /// );
+    /// ```
+    pub fn then(&self, op: UnaryOp) -> Self {
+        Self::from_fn(|a, b| op.apply(self.apply(a, b)))
+    }
+
+    /// Returns the `BinaryOp` applying `left` and `right` on the arguments before this one:
+    /// `(a, b) -> self(left(a), right(b))`.
+    pub fn precompose(&self, left: UnaryOp, right: UnaryOp) -> Self {
+        Self::from_fn(|a, b| self.apply(left.apply(a), right.apply(b)))
+    }
+
+    /// Returns the `UnaryOp` obtained by fixing the first argument: `b -> self(a, b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp, UnaryOp};
+    ///
+    /// assert_eq!(BinaryOp::MUL.left_section(Balance::BottomRight), UnaryOp::IDENTITY);
+    /// ```
+    pub const fn left_section(&self, a: Balance) -> UnaryOp {
+        UnaryOp(self.0[a.index()])
+    }
+
+    /// Returns the `UnaryOp` obtained by fixing the second argument: `a -> self(a, b)`.
+    pub fn right_section(&self, b: Balance) -> UnaryOp {
+        UnaryOp::from_fn(|a| self.apply(a, b))
+    }
+
+    /// Checks if `self(a, b) == self(b, a)` for all `a` and `b`.
+    pub fn is_commutative(&self) -> bool {
+        pairs().all(|(a, b)| self.apply(a, b) == self.apply(b, a))
+    }
+
+    /// Checks if `self(self(a, b), c) == self(a, self(b, c))` for all `a`, `b` and `c`.
+    pub fn is_associative(&self) -> bool {
+        pairs().all(|(a, b)| {
+            Balance::ALL
+                .into_iter()
+                .all(|c| self.apply(self.apply(a, b), c) == self.apply(a, self.apply(b, c)))
+        })
+    }
+
+    /// Checks if `self(a, a) == a` for all `a`.
+    pub fn is_idempotent(&self) -> bool {
+        Balance::ALL.into_iter().all(|a| self.apply(a, a) == a)
+    }
+
+    /// Returns the two-sided identity element `e` such that `self(e, a) == self(a, e) == a`
+    /// for all `a`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp};
+    ///
+    /// assert_eq!(BinaryOp::ADD.identity(), Some(Balance::Center));
+    /// assert_eq!(BinaryOp::SUB.identity(), None);
+    /// ```
+    pub fn identity(&self) -> Option<Balance> {
+        Balance::ALL.into_iter().find(|&e| {
+            Balance::ALL
+                .into_iter()
+                .all(|a| self.apply(e, a) == a && self.apply(a, e) == a)
+        })
+    }
+
+    /// Returns the two-sided absorbing element `z` such that `self(z, a) == self(a, z) == z`
+    /// for all `a`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp};
+    ///
+    /// assert_eq!(BinaryOp::MUL.absorbing(), Some(Balance::Center));
+    /// assert_eq!(BinaryOp::ADD.absorbing(), None);
+    /// ```
+    pub fn absorbing(&self) -> Option<Balance> {
+        Balance::ALL.into_iter().find(|&z| {
+            Balance::ALL
+                .into_iter()
+                .all(|a| self.apply(z, a) == z && self.apply(a, z) == z)
+        })
+    }
+
+    /// Returns the two-sided inverse of `a`: the element `b` such that
+    /// `self(a, b) == self(b, a) == e`, where `e` is the [identity](BinaryOp::identity).
+    ///
+    /// Returns `None` if the operation has no identity or `a` has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp};
+    ///
+    /// assert_eq!(BinaryOp::ADD.inverse_of(Balance::TopRight), Some(Balance::BottomLeft));
+    /// assert_eq!(BinaryOp::MUL.inverse_of(Balance::Top), None);
+    /// ```
+    pub fn inverse_of(&self, a: Balance) -> Option<Balance> {
+        let e = self.identity()?;
+        Balance::ALL
+            .into_iter()
+            .find(|&b| self.apply(a, b) == e && self.apply(b, a) == e)
+    }
+
+    /// Checks if the operation has an identity and every `Balance` has an inverse.
+    pub fn has_inverses(&self) -> bool {
+        Balance::ALL.into_iter().all(|a| self.inverse_of(a).is_some())
+    }
+
+    /// Checks if the operation distributes over `other` on both sides:
+    /// `self(a, other(b, c)) == other(self(a, b), self(a, c))` and
+    /// `self(other(b, c), a) == other(self(b, a), self(c, a))` for all `a`, `b` and `c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::BinaryOp;
+    ///
+    /// assert!(BinaryOp::MUL.distributes_over(&BinaryOp::ADD));
+    /// assert!(!BinaryOp::ADD.distributes_over(&BinaryOp::MUL));
+    /// ```
+    pub fn distributes_over(&self, other: &Self) -> bool {
+        pairs().all(|(a, b)| {
+            Balance::ALL.into_iter().all(|c| {
+                self.apply(a, other.apply(b, c)) == other.apply(self.apply(a, b), self.apply(a, c))
+                    && self.apply(other.apply(b, c), a)
+                        == other.apply(self.apply(b, a), self.apply(c, a))
+            })
+        })
+    }
+
+    /// Checks the absorption law `self(a, other(a, b)) == a` for all `a` and `b`.
+    pub fn absorbs(&self, other: &Self) -> bool {
+        pairs().all(|(a, b)| self.apply(a, other.apply(a, b)) == a)
+    }
+
+    /// Checks if the operation is associative and has an identity.
+    pub fn is_monoid(&self) -> bool {
+        self.is_associative() && self.identity().is_some()
+    }
+
+    /// Checks if the operation is a monoid where every `Balance` has an inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp};
+    ///
+    /// // Addition modulo 3 on both coordinates.
+    /// let wrapping_add = BinaryOp::from_fn(|a, b| {
+    ///     let wrap = |v: i8| (v + 1).rem_euclid(3) - 1;
+    ///     Balance::from_vector(wrap(a.x() + b.x()), wrap(a.y() + b.y()))
+    /// });
+    /// assert!(wrapping_add.is_group());
+    /// assert!(!BinaryOp::MUL.is_group());
+    /// ```
+    pub fn is_group(&self) -> bool {
+        self.is_monoid() && self.has_inverses()
+    }
+
+    /// Checks if the operation is associative, commutative and idempotent.
+    pub fn is_semilattice(&self) -> bool {
+        self.is_associative() && self.is_commutative() && self.is_idempotent()
+    }
+
+    /// Checks if the operation (as meet) and `other` (as join) form a lattice:
+    /// both are semilattices and they absorb each other.
+    pub fn forms_lattice_with(&self, other: &Self) -> bool {
+        self.is_semilattice()
+            && other.is_semilattice()
+            && self.absorbs(other)
+            && other.absorbs(self)
+    }
+}
+
+/// Returns an iterator over the 81 pairs of `Balance` variants.
+fn pairs() -> impl Iterator<Item = (Balance, Balance)> {
+    Balance::ALL
+        .into_iter()
+        .flat_map(|a| Balance::ALL.into_iter().map(move |b| (a, b)))
+}
+
+#[cfg(all(test, feature = "ternary"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        let pairs = [
+            (BinaryOp::ADD, BinaryOp::from_fn(|a, b| a + b)),
+            (BinaryOp::SUB, BinaryOp::from_fn(|a, b| a - b)),
+            (BinaryOp::MUL, BinaryOp::from_fn(|a, b| a * b)),
+            (BinaryOp::AND, BinaryOp::from_fn(|a, b| a & b)),
+            (BinaryOp::OR, BinaryOp::from_fn(|a, b| a | b)),
+            (BinaryOp::XOR, BinaryOp::from_fn(|a, b| a ^ b)),
+            (BinaryOp::K3_IMPLY, BinaryOp::from_fn(Balance::k3_imply)),
+            (BinaryOp::K3_EQUIV, BinaryOp::from_fn(Balance::k3_equiv)),
+            (BinaryOp::HT_IMPLY, BinaryOp::from_fn(Balance::ht_imply)),
+        ];
+        for (op, expected) in pairs {
+            assert_eq!(op, expected);
+        }
+    }
+
+    #[test]
+    fn test_properties() {
+        assert!(BinaryOp::MUL.is_monoid());
+        assert_eq!(BinaryOp::MUL, BinaryOp::K3_EQUIV);
+        assert!(BinaryOp::AND.is_semilattice());
+        assert!(BinaryOp::OR.distributes_over(&BinaryOp::AND));
+        assert!(BinaryOp::XOR.is_associative());
+        assert_eq!(BinaryOp::XOR.identity(), Some(Balance::TopLeft));
+        assert!(!BinaryOp::XOR.has_inverses());
+        assert!(BinaryOp::ADD.is_commutative());
+        assert!(!BinaryOp::SUB.is_commutative());
+        assert!(!BinaryOp::K3_IMPLY.is_associative());
+    }
+}
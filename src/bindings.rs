@@ -0,0 +1,271 @@
+use crate::{Balance, BalanceSet};
+
+/// A keyboard layout binding (at most) one key to each of the nine `Balance` cells.
+///
+/// The crate provides the usual layouts of grid games ([KeyLayout::NUMPAD], [KeyLayout::VI_KEYS]
+/// and [KeyLayout::WASD]) which can be remapped with [KeyLayout::with_key], or a
+/// custom layout can be built from [KeyLayout::EMPTY].
+///
+/// Keys are compared exactly, so `'w'` and `'W'` are different keys.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, KeyLayout};
+///
+/// let layout = KeyLayout::VI_KEYS;
+/// assert_eq!(layout.balance('k'), Some(Balance::Top));
+/// assert_eq!(layout.key(Balance::BottomRight), Some('n'));
+///
+/// let layout = KeyLayout::WASD;
+/// assert_eq!(layout.resolve_chord(['w', 'd']), Some(Balance::TopRight));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct KeyLayout {
+    keys: [Option<char>; 9],
+}
+
+impl KeyLayout {
+    /// A layout without any bound key.
+    pub const EMPTY: Self = Self { keys: [None; 9] };
+
+    /// The numeric keypad: `7 8 9` / `4 5 6` / `1 2 3` (`5` is `Balance::Center`).
+    pub const NUMPAD: Self = Self::new([
+        Some('7'),
+        Some('8'),
+        Some('9'),
+        Some('4'),
+        Some('5'),
+        Some('6'),
+        Some('1'),
+        Some('2'),
+        Some('3'),
+    ]);
+
+    /// The vi-keys of roguelikes: `y k u` / `h . l` / `b j n`.
+    pub const VI_KEYS: Self = Self::new([
+        Some('y'),
+        Some('k'),
+        Some('u'),
+        Some('h'),
+        Some('.'),
+        Some('l'),
+        Some('b'),
+        Some('j'),
+        Some('n'),
+    ]);
+
+    /// The WASD keys and their diagonals: `q w e` / `a _ d` / `z s c` (`Balance::Center` is unbound).
+    pub const WASD: Self = Self::new([
+        Some('q'),
+        Some('w'),
+        Some('e'),
+        Some('a'),
+        None,
+        Some('d'),
+        Some('z'),
+        Some('s'),
+        Some('c'),
+    ]);
+
+    /// Creates a `KeyLayout` from the keys of the nine cells, in the order of [Balance::to_value]
+    /// (from `TopLeft` to `BottomRight`).
+    ///
+    /// If a key is bound to several cells, [KeyLayout::balance] returns the first one.
+    pub const fn new(keys: [Option<char>; 9]) -> Self {
+        Self { keys }
+    }
+
+    /// Returns the key bound to the given `Balance`, if any.
+    pub const fn key(&self, balance: Balance) -> Option<char> {
+        self.keys[balance.index()]
+    }
+
+    /// Returns the `Balance` bound to the given key, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, KeyLayout};
+    ///
+    /// assert_eq!(KeyLayout::NUMPAD.balance('5'), Some(Balance::Center));
+    /// assert_eq!(KeyLayout::NUMPAD.balance('0'), None);
+    /// ```
+    pub const fn balance(&self, key: char) -> Option<Balance> {
+        let mut i = 0;
+        while i < 9 {
+            if let Some(bound) = self.keys[i] {
+                if bound == key {
+                    return Some(Balance::from_value(i as i8 - 4));
+                }
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns a copy of this layout where `key` is bound to `balance`.
+    ///
+    /// The key is unbound from any other cell, and the previous key of `balance` is unbound.
+    /// Passing `None` unbinds `balance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, KeyLayout};
+    ///
+    /// let layout = KeyLayout::WASD.with_key(Balance::Center, Some('x'));
+    /// assert_eq!(layout.balance('x'), Some(Balance::Center));
+    ///
+    /// let layout = layout.with_key(Balance::Bottom, Some('x'));
+    /// assert_eq!(layout.balance('x'), Some(Balance::Bottom));
+    /// assert_eq!(layout.key(Balance::Center), None);
+    /// assert_eq!(layout.balance('s'), None);
+    /// ```
+    pub const fn with_key(self, balance: Balance, key: Option<char>) -> Self {
+        let mut keys = self.keys;
+        if let Some(key) = key {
+            let mut i = 0;
+            while i < 9 {
+                if let Some(bound) = keys[i] {
+                    if bound == key {
+                        keys[i] = None;
+                    }
+                }
+                i += 1;
+            }
+        }
+        keys[balance.index()] = key;
+        Self { keys }
+    }
+
+    /// Resolves a chord of simultaneously pressed keys into a single `Balance`.
+    ///
+    /// A chord is a set of keys: their order does not matter and a repeated key counts once.
+    /// The components of the directions of the bound keys are summed, then reduced to their
+    /// signs, so that two orthogonal keys produce a diagonal and two opposite keys cancel each
+    /// other. Unbound keys are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The combined `Balance`, or `None` if no key of the chord is bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, KeyLayout};
+    ///
+    /// let layout = KeyLayout::WASD;
+    /// assert_eq!(layout.resolve_chord(['w', 'd']), Some(Balance::TopRight));
+    /// assert_eq!(layout.resolve_chord(['a', 'd']), Some(Balance::Center));
+    /// assert_eq!(layout.resolve_chord(['s', 'x']), Some(Balance::Bottom));
+    /// assert_eq!(layout.resolve_chord(['w', 'w', 's']), Some(Balance::Center));
+    /// assert_eq!(layout.resolve_chord(['x']), None);
+    /// ```
+    pub fn resolve_chord(&self, keys: impl IntoIterator<Item = char>) -> Option<Balance> {
+        let pressed: BalanceSet = keys
+            .into_iter()
+            .filter_map(|key| self.balance(key))
+            .collect();
+        if pressed.is_empty() {
+            return None;
+        }
+        let (x, y) = pressed
+            .iter()
+            .fold((0, 0), |(x, y), balance| (x + balance.x(), y + balance.y()));
+        Some(Balance::from_vector(x.signum(), y.signum()))
+    }
+}
+
+impl Balance {
+    /// Constructs a `Balance` from a numeric keypad digit.
+    ///
+    /// The digits are laid out as on a keypad: `7 8 9` / `4 5 6` / `1 2 3`.
+    ///
+    /// # Returns
+    ///
+    /// The `Balance` of the digit, or `None` if the digit is not in the range `1..=9`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_numpad(8), Some(Balance::Top));
+    /// assert_eq!(Balance::from_numpad(5), Some(Balance::Center));
+    /// assert_eq!(Balance::from_numpad(1), Some(Balance::BottomLeft));
+    /// assert_eq!(Balance::from_numpad(0), None);
+    /// ```
+    pub const fn from_numpad(digit: u8) -> Option<Self> {
+        match digit {
+            1..=9 => {
+                let digit = digit as i8 - 1;
+                Some(Self::from_vector(digit % 3 - 1, 1 - digit / 3))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts the current `Balance` into its numeric keypad digit (`1..=9`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::TopRight.to_numpad(), 9);
+    /// assert_eq!(Balance::Center.to_numpad(), 5);
+    /// assert_eq!(Balance::Bottom.to_numpad(), 2);
+    /// ```
+    pub const fn to_numpad(self) -> u8 {
+        let (x, y) = self.to_vector();
+        ((1 - y) * 3 + x + 2) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, KeyLayout};
+
+    #[test]
+    fn test_numpad_layout() {
+        for digit in 1..=9 {
+            let balance = Balance::from_numpad(digit).unwrap();
+            assert_eq!(balance.to_numpad(), digit);
+            let key = char::from(b'0' + digit);
+            assert_eq!(KeyLayout::NUMPAD.balance(key), Some(balance));
+            assert_eq!(KeyLayout::NUMPAD.key(balance), Some(key));
+        }
+    }
+
+    #[test]
+    fn test_layouts_agree() {
+        for digit in 1..=9 {
+            let balance = Balance::from_numpad(digit).unwrap();
+            if let Some(key) = KeyLayout::WASD.key(balance) {
+                assert_eq!(KeyLayout::WASD.balance(key), Some(balance));
+            }
+            let key = KeyLayout::VI_KEYS.key(balance).unwrap();
+            assert_eq!(KeyLayout::VI_KEYS.balance(key), Some(balance));
+        }
+    }
+
+    #[test]
+    fn test_chord_order() {
+        // Every order of the keys, `d` being pressed twice.
+        let keys = ['w', 'd', 'a', 'd'];
+        for a in 0..4 {
+            for b in (0..4).filter(|&b| b != a) {
+                for c in (0..4).filter(|&c| c != a && c != b) {
+                    let d = 6 - a - b - c;
+                    let chord = [keys[a], keys[b], keys[c], keys[d]];
+                    assert_eq!(KeyLayout::WASD.resolve_chord(chord), Some(Balance::Top));
+                }
+            }
+        }
+        assert_eq!(
+            KeyLayout::WASD.resolve_chord(['w', 's', 'w']),
+            KeyLayout::WASD.resolve_chord(['w', 'w', 's'])
+        );
+    }
+}
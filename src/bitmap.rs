@@ -0,0 +1,387 @@
+use crate::{BalanceError, Coordinate, Position};
+use alloc::vec::Vec;
+
+/// A binary image: a grid of `width` x `height` cells, each set or not.
+///
+/// The cells are stored row by row, from the top-left corner, so that the cell at
+/// [Position] `(x, y)` is at index `y * width + x`, with the y-axis pointing down as for
+/// the movements of a `Balance`.
+///
+/// A `Bitmap` can be read from a PBM or a PGM image ([Bitmap::from_pbm],
+/// [Bitmap::from_pgm]), and its regions outlined with [Bitmap::contours].
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Bitmap, Position};
+///
+/// let mut bitmap = Bitmap::new(3, 2);
+/// bitmap.set(Position::new(1, 1), true);
+/// assert!(bitmap.get(Position::new(1, 1)));
+/// assert!(!bitmap.get(Position::new(0, 0)));
+/// assert!(!bitmap.get(Position::new(-1, 5)));
+/// assert_eq!(bitmap.as_slice(), &[false, false, false, false, true, false]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Eq, Hash, Default)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+}
+
+impl Bitmap {
+    /// Creates a `Bitmap` of `width` x `height` cells, none of them set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells overflows `usize`.
+    pub fn new(width: usize, height: usize) -> Self {
+        match Self::try_new(width, height) {
+            Ok(bitmap) => bitmap,
+            Err(_) => panic!("Invalid size: the number of cells overflows usize."),
+        }
+    }
+
+    /// Creates a `Bitmap` of `width` x `height` cells, none of them set, without panicking.
+    ///
+    /// This is the fallible counterpart of [Bitmap::new].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidImage] if the number of cells overflows `usize`.
+    pub fn try_new(width: usize, height: usize) -> Result<Self, BalanceError> {
+        let len = width
+            .checked_mul(height)
+            .ok_or(BalanceError::InvalidImage)?;
+        Ok(Self {
+            width,
+            height,
+            cells: alloc::vec![false; len],
+        })
+    }
+
+    /// Creates a `Bitmap` of `width` x `height` cells from the cells, row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidImage] if the number of cells overflows `usize`, and
+    /// [BalanceError::LengthMismatch] if there are not `width * height` cells.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<bool>) -> Result<Self, BalanceError> {
+        let len = width
+            .checked_mul(height)
+            .ok_or(BalanceError::InvalidImage)?;
+        if cells.len() != len {
+            return Err(BalanceError::LengthMismatch(cells.len(), len));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Reads a PBM image, in the plain (`P1`) or the raw (`P4`) format.
+    ///
+    /// The black pixels (`1`) are the set cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidImage] if the bytes are not a PBM image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Bitmap, Position};
+    ///
+    /// let bitmap = Bitmap::from_pbm(b"P1\n# a dot\n3 2\n0 1 0\n0 0 0\n").unwrap();
+    /// assert_eq!((bitmap.width(), bitmap.height()), (3, 2));
+    /// assert!(bitmap.get(Position::new(1, 0)));
+    ///
+    /// let raw = Bitmap::from_pbm(b"P4 3 2\n\x40\x00").unwrap();
+    /// assert_eq!(raw, bitmap);
+    /// ```
+    pub fn from_pbm(bytes: &[u8]) -> Result<Self, BalanceError> {
+        let mut reader = Reader::new(bytes);
+        let raw = match reader.magic()? {
+            b"P1" => false,
+            b"P4" => true,
+            _ => return Err(BalanceError::InvalidImage),
+        };
+        let (width, height) = (reader.number()?, reader.number()?);
+        let count = width
+            .checked_mul(height)
+            .ok_or(BalanceError::InvalidImage)?;
+        let cells = if raw {
+            let row_bytes = width.div_ceil(8);
+            let len = row_bytes
+                .checked_mul(height)
+                .ok_or(BalanceError::InvalidImage)?;
+            let data = reader.raster(len)?;
+            (0..count)
+                .map(|i| {
+                    let (x, y) = (i % width, i / width);
+                    data[y * row_bytes + x / 8] & (0x80 >> (x % 8)) != 0
+                })
+                .collect()
+        } else {
+            (0..count)
+                .map(|_| match reader.token_byte()? {
+                    b'0' => Ok(false),
+                    b'1' => Ok(true),
+                    _ => Err(BalanceError::InvalidImage),
+                })
+                .collect::<Result<_, _>>()?
+        };
+        Self::from_cells(width, height, cells)
+    }
+
+    /// Reads a PGM image, in the plain (`P2`) or the raw (`P5`) format.
+    ///
+    /// The pixels darker than `threshold` (with a gray value strictly less than it) are the
+    /// set cells, so that the dark pixels are set, as with [Bitmap::from_pbm].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidImage] if the bytes are not a PGM image, or if a gray
+    /// value is greater than the maximum value of the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from_pgm(b"P2\n3 1\n255\n0 128 255\n", 128).unwrap();
+    /// assert_eq!(bitmap.as_slice(), &[true, false, false]);
+    ///
+    /// let raw = Bitmap::from_pgm(b"P5 3 1 255\n\x00\x80\xff", 129).unwrap();
+    /// assert_eq!(raw.as_slice(), &[true, true, false]);
+    /// ```
+    pub fn from_pgm(bytes: &[u8], threshold: u16) -> Result<Self, BalanceError> {
+        let mut reader = Reader::new(bytes);
+        let raw = match reader.magic()? {
+            b"P2" => false,
+            b"P5" => true,
+            _ => return Err(BalanceError::InvalidImage),
+        };
+        let (width, height, max) = (reader.number()?, reader.number()?, reader.number()?);
+        if max == 0 || max > u16::MAX as usize {
+            return Err(BalanceError::InvalidImage);
+        }
+        let count = width
+            .checked_mul(height)
+            .ok_or(BalanceError::InvalidImage)?;
+        let sample = |value: usize| match value {
+            value if value > max => Err(BalanceError::InvalidImage),
+            value => Ok(value < threshold as usize),
+        };
+        let cells = if raw {
+            let size = if max < 256 { 1 } else { 2 };
+            let len = count.checked_mul(size).ok_or(BalanceError::InvalidImage)?;
+            let data = reader.raster(len)?;
+            data.chunks(size)
+                .map(|value| sample(value.iter().fold(0, |sum, &b| sum << 8 | b as usize)))
+                .collect::<Result<_, _>>()?
+        } else {
+            (0..count)
+                .map(|_| reader.number().and_then(sample))
+                .collect::<Result<_, _>>()?
+        };
+        Self::from_cells(width, height, cells)
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells, row by row.
+    pub fn as_slice(&self) -> &[bool] {
+        &self.cells
+    }
+
+    /// Consumes the `Bitmap` and returns its cells, row by row.
+    pub fn into_cells(self) -> Vec<bool> {
+        self.cells
+    }
+
+    /// Returns the index of the cell at `position`, or `None` if it is outside of the `Bitmap`.
+    fn index<T: Coordinate>(&self, position: Position<T>) -> Option<usize> {
+        let (x, y) = (position.x.to_i128(), position.y.to_i128());
+        if x < 0 || y < 0 || x >= self.width as i128 || y >= self.height as i128 {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    /// Checks whether the cell at `position` is set. The cells outside of the `Bitmap` are not.
+    pub fn get<T: Coordinate>(&self, position: Position<T>) -> bool {
+        self.index(position).is_some_and(|index| self.cells[index])
+    }
+
+    /// Sets or clears the cell at `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is outside of the `Bitmap`.
+    pub fn set<T: Coordinate>(&mut self, position: Position<T>, value: bool) {
+        let index = self.index(position).expect("Invalid position");
+        self.cells[index] = value;
+    }
+
+    /// Returns the positions of the set cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, &set)| set)
+            .map(|(index, _)| {
+                Position::new((index % self.width) as i32, (index / self.width) as i32)
+            })
+    }
+}
+
+/// Reads the header and the raster of a Netpbm image.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Reads the two bytes of the magic number.
+    fn magic(&mut self) -> Result<&'a [u8], BalanceError> {
+        let magic = self.bytes.get(..2).ok_or(BalanceError::InvalidImage)?;
+        self.position = 2;
+        Ok(magic)
+    }
+
+    /// Skips the whitespace and the comments, from `#` to the end of the line.
+    fn skip_blanks(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.position) {
+            if byte == b'#' {
+                while self.bytes.get(self.position).is_some_and(|&b| b != b'\n') {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Reads the next byte after the blanks.
+    fn token_byte(&mut self) -> Result<u8, BalanceError> {
+        self.skip_blanks();
+        let byte = *self
+            .bytes
+            .get(self.position)
+            .ok_or(BalanceError::InvalidImage)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    /// Reads the next decimal number after the blanks.
+    fn number(&mut self) -> Result<usize, BalanceError> {
+        self.skip_blanks();
+        let start = self.position;
+        let mut value: usize = 0;
+        while let Some(&digit) = self.bytes.get(self.position).filter(|b| b.is_ascii_digit()) {
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add((digit - b'0') as usize))
+                .ok_or(BalanceError::InvalidImage)?;
+            self.position += 1;
+        }
+        if self.position == start {
+            return Err(BalanceError::InvalidImage);
+        }
+        Ok(value)
+    }
+
+    /// Skips the single whitespace ending the header, and returns the next `len` bytes.
+    fn raster(&mut self, len: usize) -> Result<&'a [u8], BalanceError> {
+        if !self
+            .bytes
+            .get(self.position)
+            .is_some_and(|b| b.is_ascii_whitespace())
+        {
+            return Err(BalanceError::InvalidImage);
+        }
+        let start = self.position + 1;
+        self.bytes
+            .get(start..start.checked_add(len).ok_or(BalanceError::InvalidImage)?)
+            .ok_or(BalanceError::InvalidImage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BalanceError, Bitmap};
+    use alloc::format;
+    use alloc::vec;
+
+    #[test]
+    fn test_oversized_images() {
+        let huge = usize::MAX / 2 + 1;
+        for header in [
+            format!("P1 {} 3\n", huge),
+            format!("P4 {} {}\n", huge, huge),
+            format!("P2 {} 3 255\n", huge),
+            format!("P5 {} 1 65535\n", huge),
+            format!("P5 1{} 1 255\n", usize::MAX),
+        ] {
+            let bytes = header.as_bytes();
+            let image = match bytes[1] {
+                b'1' | b'4' => Bitmap::from_pbm(bytes),
+                _ => Bitmap::from_pgm(bytes, 128),
+            };
+            assert_eq!(image, Err(BalanceError::InvalidImage), "{}", header);
+        }
+        assert_eq!(
+            Bitmap::from_cells(usize::MAX, 2, vec![]),
+            Err(BalanceError::InvalidImage)
+        );
+        assert_eq!(
+            Bitmap::try_new(2, usize::MAX),
+            Err(BalanceError::InvalidImage)
+        );
+    }
+
+    #[test]
+    fn test_truncated_images() {
+        let truncated: [&[u8]; 7] = [
+            b"",
+            b"P",
+            b"P1 3",
+            b"P1 2 2 0 1 1",
+            b"P4 9 2\n\x00\x00\x00",
+            b"P5 2 2 255",
+            b"P5 2 1 256\n\x00\x01\x00",
+        ];
+        for bytes in truncated {
+            let image = match bytes.get(1) {
+                Some(b'5') => Bitmap::from_pgm(bytes, 128),
+                _ => Bitmap::from_pbm(bytes),
+            };
+            assert_eq!(image, Err(BalanceError::InvalidImage));
+        }
+        assert_eq!(
+            Bitmap::from_pgm(b"P2 2 1 15 3 16", 8),
+            Err(BalanceError::InvalidImage)
+        );
+        assert_eq!(
+            Bitmap::from_pgm(b"P5 1 1 100\n\x65", 8),
+            Err(BalanceError::InvalidImage)
+        );
+        assert!(Bitmap::from_pgm(b"P2 2 1 15 3 15", 8).is_ok());
+    }
+}
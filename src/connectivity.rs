@@ -0,0 +1,46 @@
+use crate::{Balance, BalanceSet};
+
+/// Selects which cells of a grid are neighbours: the cells sharing an edge, or also the
+/// cells sharing a corner.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Connectivity};
+///
+/// assert!(Connectivity::Eight.allows(Balance::TopRight));
+/// assert!(!Connectivity::Four.allows(Balance::TopRight));
+/// assert_eq!(Connectivity::Four.neighbours().len(), 4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum Connectivity {
+    /// The four cells sharing an edge: only straight movements.
+    Four,
+    /// The eight surrounding cells: straight and diagonal movements.
+    #[default]
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the movements leading to a neighbour: [BalanceSet::EDGES] or
+    /// [BalanceSet::DIRECTIONS].
+    pub const fn neighbours(self) -> BalanceSet {
+        match self {
+            Connectivity::Four => BalanceSet::EDGES,
+            Connectivity::Eight => BalanceSet::DIRECTIONS,
+        }
+    }
+
+    /// Checks whether `movement` leads to a neighbour.
+    pub const fn allows(self, movement: Balance) -> bool {
+        self.neighbours().contains(movement)
+    }
+
+    /// Returns the number of neighbours of a cell: `4` or `8`.
+    pub const fn count(self) -> u8 {
+        match self {
+            Connectivity::Four => 4,
+            Connectivity::Eight => 8,
+        }
+    }
+}
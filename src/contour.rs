@@ -0,0 +1,496 @@
+use crate::{Balance, Bitmap, Connectivity, Path, Position};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The neighbours of a cell, clockwise on the grid (whose y-axis points down) from the left.
+const RING: [Balance; 8] = [
+    Balance::Left,
+    Balance::TopLeft,
+    Balance::Top,
+    Balance::TopRight,
+    Balance::Right,
+    Balance::BottomRight,
+    Balance::Bottom,
+    Balance::BottomLeft,
+];
+
+/// The label of the background cells already visited.
+const BACKGROUND: usize = usize::MAX;
+
+/// The outline of a region (or of a hole in a region) of a binary image.
+///
+/// The contour is a closed [Path] going through the boundary cells of the region, from
+/// `start`. Following the Moore-neighbour or the square-tracing algorithm, the region is
+/// always on the right of the route: the outer contours go clockwise on the grid and the
+/// contours of the holes go counterclockwise (see [Orientation](crate::Orientation)).
+///
+/// The regions are the sets of connected set cells, the neighbours of a cell being chosen
+/// by a [Connectivity]: only the movements which are
+/// [is_orthogonal](Balance::is_orthogonal) for [Connectivity::Four], the
+/// [is_diagonal](Balance::is_diagonal) ones as well for [Connectivity::Eight]. The holes are
+/// the regions of unset cells which do not touch the border of the image, with the other
+/// connectivity, so that a region is never both inside and outside of a contour.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Connectivity, Contour, Orientation, Position};
+///
+/// // A square ring around a hole.
+/// let cells = [
+///     true, true, true,
+///     true, false, true,
+///     true, true, true,
+/// ];
+/// let contours = Contour::trace(&cells, 3, Connectivity::Eight);
+/// assert_eq!(contours.len(), 2);
+///
+/// let outer = &contours[0];
+/// assert_eq!(outer.start, Position::new(0, 0));
+/// assert_eq!(outer.path.len(), 8);
+/// assert_eq!(outer.path[0], Balance::Right);
+/// assert_eq!(outer.path.orientation(), Some(Orientation::Clockwise));
+///
+/// let hole = &contours[1];
+/// assert!(hole.is_hole);
+/// assert!(hole.path.is_closed());
+/// assert_eq!(hole.path.orientation(), Some(Orientation::Counterclockwise));
+/// ```
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct Contour {
+    /// The first cell of the contour, in the row-by-row order of the image.
+    pub start: Position,
+    /// The closed route around the region, from `start`. It is empty for a lone cell.
+    pub path: Path,
+    /// Whether the contour surrounds a hole instead of a region.
+    pub is_hole: bool,
+}
+
+impl Contour {
+    /// Traces the contours of the regions of `cells` and of their holes with the
+    /// Moore-neighbour algorithm (and Jacob's stopping criterion).
+    ///
+    /// The `cells` are given row by row, `width` cells per row. The contours are returned in
+    /// the order their start cells are met, row by row, each region before its holes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn trace(cells: &[bool], width: usize, connectivity: Connectivity) -> Vec<Self> {
+        let ring: Vec<Balance> = RING
+            .into_iter()
+            .filter(|&movement| connectivity.allows(movement))
+            .collect();
+        Labels::new(cells, width).contours(connectivity, |inside, start, backtrack| {
+            moore(inside, start, &ring, backtrack)
+        })
+    }
+
+    /// Traces the contours of the 4-connected regions of `cells` and of their holes with the
+    /// square-tracing algorithm (and Jacob's stopping criterion).
+    ///
+    /// The route only moves orthogonally: where the classic algorithm would cut a corner to
+    /// a cell only diagonal to the last one, the tracer goes back to check the cell between
+    /// them. See [Contour::trace] for the layout of the `cells` and the order of the
+    /// contours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Connectivity, Contour};
+    ///
+    /// let cells = [
+    ///     true, true, false,
+    ///     true, true, true,
+    /// ];
+    /// let square = Contour::trace_square(&cells, 3);
+    /// assert_eq!(square.len(), 1);
+    /// assert!(square[0].path.is_closed());
+    /// ```
+    pub fn trace_square(cells: &[bool], width: usize) -> Vec<Self> {
+        Labels::new(cells, width).contours(Connectivity::Four, |inside, start, backtrack| {
+            square(inside, start, backtrack.rotate_right())
+        })
+    }
+}
+
+impl Bitmap {
+    /// Traces the contours of the regions of this `Bitmap` and of their holes, with the
+    /// Moore-neighbour algorithm (see [Contour::trace]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Bitmap, Connectivity};
+    ///
+    /// let bitmap = Bitmap::from_pbm(b"P1 4 2 1000 0100").unwrap();
+    /// assert_eq!(bitmap.contours(Connectivity::Eight).len(), 1);
+    /// assert_eq!(bitmap.contours(Connectivity::Four).len(), 2);
+    /// ```
+    pub fn contours(&self, connectivity: Connectivity) -> Vec<Contour> {
+        Contour::trace(self.as_slice(), self.width(), connectivity)
+    }
+
+    /// Traces the contours of the 4-connected regions of this `Bitmap` and of their holes,
+    /// with the square-tracing algorithm (see [Contour::trace_square]).
+    pub fn square_contours(&self) -> Vec<Contour> {
+        Contour::trace_square(self.as_slice(), self.width())
+    }
+}
+
+/// The cells of an image, labelled with the region they belong to.
+struct Labels<'a> {
+    cells: &'a [bool],
+    width: usize,
+    height: usize,
+    labels: Vec<usize>,
+}
+
+impl<'a> Labels<'a> {
+    fn new(cells: &'a [bool], width: usize) -> Self {
+        let height = match width {
+            0 if cells.is_empty() => 0,
+            0 => panic!("Invalid width"),
+            _ => {
+                assert!(cells.len().is_multiple_of(width), "Invalid width");
+                cells.len() / width
+            }
+        };
+        Self {
+            cells,
+            width,
+            height,
+            labels: vec![0; cells.len()],
+        }
+    }
+
+    /// Returns the index of the cell at `position`, or `None` if it is outside of the image.
+    fn index(&self, position: Position) -> Option<usize> {
+        let (x, y) = (position.x as usize, position.y as usize);
+        (position.x >= 0 && position.y >= 0 && x < self.width && y < self.height)
+            .then(|| y * self.width + x)
+    }
+
+    /// Returns the position of the cell at `index`.
+    fn position(&self, index: usize) -> Position {
+        Position::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    /// Returns the label of the cell at `position`, `0` outside of the image.
+    fn label(&self, position: Position) -> usize {
+        self.index(position).map_or(0, |index| self.labels[index])
+    }
+
+    /// Gives `label` to the cells connected to the cell at `index` and set like it, and
+    /// returns whether one of them is on the border of the image.
+    fn fill(&mut self, index: usize, label: usize, connectivity: Connectivity) -> bool {
+        let set = self.cells[index];
+        let mut border = false;
+        let mut stack = vec![index];
+        self.labels[index] = label;
+        while let Some(index) = stack.pop() {
+            let position = self.position(index);
+            for movement in connectivity.neighbours() {
+                match self.index(position + movement) {
+                    Some(next) if self.labels[next] == 0 && self.cells[next] == set => {
+                        self.labels[next] = label;
+                        stack.push(next);
+                    }
+                    Some(_) => {}
+                    None => border = true,
+                }
+            }
+        }
+        border
+    }
+
+    /// Labels the regions and the holes, and traces their contours with `follow`.
+    ///
+    /// `follow` is given the cells of the region, the start cell, and the movement from it
+    /// to an unset cell, from which the search for the next cell begins.
+    fn contours(
+        mut self,
+        connectivity: Connectivity,
+        follow: impl Fn(&dyn Fn(Position) -> bool, Position, Balance) -> Path,
+    ) -> Vec<Contour> {
+        let background = match connectivity {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        };
+        let mut contours = Vec::new();
+        let mut regions = 0;
+        for index in 0..self.cells.len() {
+            if self.labels[index] != 0 {
+                continue;
+            }
+            let position = self.position(index);
+            let (start, backtrack, label, is_hole) = if self.cells[index] {
+                regions += 1;
+                self.fill(index, regions, connectivity);
+                // The cells before the first cell of a region, in the row-by-row order, are
+                // not in it.
+                (position, Balance::Left, regions, false)
+            } else {
+                if self.fill(index, BACKGROUND, background) {
+                    continue;
+                }
+                // The cell above the first cell of a hole is in the surrounding region.
+                let start = position + Balance::Top;
+                (start, Balance::Bottom, self.label(start), true)
+            };
+            let inside = |position| self.label(position) == label;
+            contours.push(Contour {
+                start,
+                path: follow(&inside, start, backtrack),
+                is_hole,
+            });
+        }
+        contours
+    }
+}
+
+/// Follows the contour from `start` with the Moore-neighbour algorithm, searching the next
+/// cell clockwise among the `ring` of neighbours, from the one after `backtrack`.
+fn moore(
+    inside: &dyn Fn(Position) -> bool,
+    start: Position,
+    ring: &[Balance],
+    backtrack: Balance,
+) -> Path {
+    let n = ring.len();
+    let mut candidate = ring
+        .iter()
+        .position(|&movement| movement == backtrack)
+        .unwrap_or(0)
+        + 1;
+    let mut position = start;
+    let mut first = None;
+    let mut path = Path::default();
+    while let Some(found) = (candidate..candidate + n)
+        .map(|k| k % n)
+        .find(|&k| inside(position + ring[k]))
+    {
+        // Jacob's stopping criterion: leaving the start again the way it was first left.
+        if position == start && first == Some(found) {
+            break;
+        }
+        first.get_or_insert(found);
+        path.push(ring[found]);
+        position += ring[found];
+        // The search resumes after the unset cell checked before the one moved to.
+        candidate = match n {
+            8 => found + 7 - found % 2,
+            _ => found + 3,
+        };
+    }
+    path
+}
+
+/// Follows the contour from `start` with the square-tracing algorithm, entering `start`
+/// heading in `heading`: turning left on the set cells and right on the others.
+///
+/// The route never cuts a corner: instead of stepping on a set cell only diagonal to the
+/// last one, the tracer turns right once more, back to the last cell, so that the cell
+/// between them is checked first. The tracing stops when the tracer enters `start` again
+/// heading the way it already did, the route being the movements between the two.
+fn square(inside: &dyn Fn(Position) -> bool, start: Position, heading: Balance) -> Path {
+    let (mut position, mut heading, mut last) = (start, heading, start);
+    let mut movements = Vec::new();
+    let mut entries = vec![(heading, 0)];
+    loop {
+        if inside(position) {
+            heading = heading.rotate_left();
+        } else {
+            heading = heading.rotate_right();
+            let next = position + heading;
+            if inside(next) && last.direction_to(next).is_corner() {
+                heading = heading.rotate_right();
+            }
+        }
+        position += heading;
+        if inside(position) && position != last {
+            movements.push(last.direction_to(position));
+            last = position;
+        }
+        if position == start {
+            if let Some(&(_, len)) = entries.iter().find(|&&(entry, _)| entry == heading) {
+                return Path::new(movements.split_off(len));
+            }
+            entries.push((heading, movements.len()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Connectivity, Contour, Orientation, Position};
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+
+    /// Returns pseudo-random cells, about half of them set.
+    fn random_cells(seed: u64, len: usize) -> alloc::vec::Vec<bool> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                state >> 63 == 1
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ring() {
+        let mut cells = [true; 25];
+        cells[12] = false;
+        for contours in [
+            Contour::trace(&cells, 5, Connectivity::Eight),
+            Contour::trace(&cells, 5, Connectivity::Four),
+            Contour::trace_square(&cells, 5),
+        ] {
+            assert_eq!(contours.len(), 2);
+            assert_eq!(contours[0].path.len(), 16);
+            assert_eq!(contours[0].path.orientation(), Some(Orientation::Clockwise));
+            assert!(contours[1].is_hole);
+            assert_eq!(
+                contours[1].path.orientation(),
+                Some(Orientation::Counterclockwise)
+            );
+        }
+    }
+
+    #[test]
+    fn test_lone_cells() {
+        let cells = [true, false, false, true];
+        assert_eq!(Contour::trace(&cells, 2, Connectivity::Four).len(), 2);
+        let contours = Contour::trace(&cells, 2, Connectivity::Eight);
+        assert_eq!(contours.len(), 1);
+        assert_eq!(contours[0].path.len(), 2);
+        for contour in Contour::trace_square(&cells, 2) {
+            assert!(contour.path.is_empty());
+        }
+        assert!(Contour::trace(&[], 0, Connectivity::Eight).is_empty());
+    }
+
+    #[test]
+    fn test_square_corners() {
+        // The top right cells of the region are only 4-connected to the rest through its
+        // bottom row, around the unset cell at (6, 1).
+        let rows = ["..#####.##.", "##.###.###.", "#.#.####.##"];
+        let cells: Vec<bool> = rows
+            .iter()
+            .flat_map(|row| row.bytes().map(|cell| cell == b'#'))
+            .collect();
+        let contours = Contour::trace_square(&cells, 11);
+        let outer = &contours[0];
+        assert_eq!(outer.start, Position::new(2, 0));
+        let route: BTreeSet<Position> = outer.path.positions(outer.start).collect();
+        assert!(route.contains(&Position::new(4, 1)) && route.contains(&Position::new(5, 1)));
+        let interior = outer.path.interior_cells(outer.start);
+        assert!(!interior.contains(&Position::new(6, 1)));
+        assert_eq!(
+            interior,
+            Contour::trace(&cells, 11, Connectivity::Four)[0]
+                .path
+                .interior_cells(outer.start)
+        );
+    }
+
+    #[test]
+    fn test_random_images() {
+        for seed in 0..200 {
+            let (width, height) = (1 + seed as usize % 9, 1 + seed as usize % 7);
+            let cells = random_cells(seed, width * height);
+            let at = |x: i32, y: i32| {
+                x >= 0
+                    && y >= 0
+                    && (x as usize) < width
+                    && (y as usize) < height
+                    && cells[y as usize * width + x as usize]
+            };
+            for (connectivity, contours) in [
+                (
+                    Connectivity::Eight,
+                    Contour::trace(&cells, width, Connectivity::Eight),
+                ),
+                (
+                    Connectivity::Four,
+                    Contour::trace(&cells, width, Connectivity::Four),
+                ),
+                (Connectivity::Four, Contour::trace_square(&cells, width)),
+            ] {
+                for contour in contours {
+                    assert!(contour.path.is_closed());
+                    if connectivity == Connectivity::Four {
+                        assert!(contour.path.iter().all(|movement| movement.is_edge()));
+                    }
+                    for position in contour.path.positions(contour.start) {
+                        assert!(at(position.x, position.y), "seed {}", seed);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_interiors() {
+        for seed in 0..500 {
+            let (width, height) = (1 + seed as usize % 11, 1 + seed as usize % 8);
+            let cells = random_cells(seed, width * height);
+            let (w, h) = (width as i32, height as i32);
+            let within = |p: Position| p.x >= 0 && p.y >= 0 && p.x < w && p.y < h;
+            let at = |p: Position| within(p) && cells[(p.y * w + p.x) as usize];
+            for (tracer, (connectivity, contours)) in [
+                (
+                    Connectivity::Eight,
+                    Contour::trace(&cells, width, Connectivity::Eight),
+                ),
+                (
+                    Connectivity::Four,
+                    Contour::trace(&cells, width, Connectivity::Four),
+                ),
+                (Connectivity::Four, Contour::trace_square(&cells, width)),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let background = match connectivity {
+                    Connectivity::Four => Connectivity::Eight,
+                    Connectivity::Eight => Connectivity::Four,
+                };
+                for contour in contours {
+                    // The region along with its holes (and whatever they contain) is made of
+                    // the cells which cannot reach the outside of the image around it.
+                    let region: BTreeSet<Position> = connectivity
+                        .flood_fill(contour.start, at)
+                        .into_iter()
+                        .collect();
+                    let outside: BTreeSet<Position> = background
+                        .flood_fill(Position::new(-1, -1), |p| {
+                            p.x >= -1 && p.y >= -1 && p.x <= w && p.y <= h && !region.contains(&p)
+                        })
+                        .into_iter()
+                        .collect();
+                    let filled: Vec<Position> = (0..h)
+                        .flat_map(|y| (0..w).map(move |x| Position::new(x, y)))
+                        .filter(|p| !outside.contains(p))
+                        .collect();
+                    let interior = contour.path.interior_cells(contour.start);
+                    if contour.is_hole {
+                        let hole = background
+                            .flood_fill(contour.start + Balance::Bottom, |p| within(p) && !at(p));
+                        assert!(hole.iter().all(|p| interior.contains(p)), "seed {}", seed);
+                        assert!(interior.iter().all(|p| filled.contains(p)), "seed {}", seed);
+                    } else {
+                        assert_eq!(interior, filled, "seed {}, tracer {}", seed, tracer);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Balance, BalanceError};
+
+/// Selects the coordinate system used to express vectors and angles of a `Balance`.
+///
+/// By default, `Balance` uses screen coordinates for vectors ([Balance::to_vector]: `Top` is `(0, -1)`)
+/// and math coordinates for angles ([Balance::to_angle]: `Top` is `90°`). The `*_in` methods of
+/// `Balance` accept a `Convention` to choose one system for both.
+///
+/// | Convention      | Vector of `Top` | Vector of `Right` | Angle of `Top` | Angle of `Right` |
+/// |-----------------|-----------------|-------------------|----------------|------------------|
+/// | `ScreenYDown`   | `(0, -1)`       | `(1, 0)`          | `-90°`         | `0°`             |
+/// | `MathYUp`       | `(0, 1)`        | `(1, 0)`          | `90°`          | `0°`             |
+/// | `CompassBearing`| `(0, 1)`        | `(1, 0)`          | `0°`           | `90°`            |
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Convention};
+///
+/// let balance = Balance::TopLeft;
+/// assert_eq!(balance.to_vector_in(Convention::ScreenYDown), (-1, -1));
+/// assert_eq!(balance.to_vector_in(Convention::MathYUp), (-1, 1));
+/// assert_eq!(balance.to_angle_in(Convention::CompassBearing), 315.0);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Convention {
+    /// `x` grows rightward and `y` grows downward. Angles are in degrees in the range
+    /// `(-180.0, 180.0]`, measured clockwise from the x-axis (`Bottom` is `90°`).
+    ScreenYDown,
+    /// `x` grows rightward and `y` grows upward. Angles are in degrees in the range
+    /// `(-180.0, 180.0]`, measured counterclockwise from the x-axis (`Top` is `90°`).
+    MathYUp,
+    /// `x` grows eastward and `y` grows northward. Angles are bearings in degrees in the range
+    /// `[0.0, 360.0)`, measured clockwise from the north (`Top` is `0°`, `Right` is `90°`).
+    CompassBearing,
+}
+
+impl Balance {
+    /// Converts the current `Balance` variant into a 2D vector `(i8, i8)` in the given `Convention`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Convention};
+    ///
+    /// assert_eq!(Balance::Top.to_vector_in(Convention::ScreenYDown), (0, -1));
+    /// assert_eq!(Balance::Top.to_vector_in(Convention::MathYUp), (0, 1));
+    /// assert_eq!(Balance::Top.to_vector_in(Convention::CompassBearing), (0, 1));
+    /// ```
+    pub const fn to_vector_in(self, convention: Convention) -> (i8, i8) {
+        let (x, y) = self.to_vector();
+        match convention {
+            Convention::ScreenYDown => (x, y),
+            Convention::MathYUp | Convention::CompassBearing => (x, -y),
+        }
+    }
+
+    /// Converts a pair of integers `(a, b)` expressed in the given `Convention` into the
+    /// corresponding `Balance` variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided `(a, b)` pair does not correspond to a valid `Balance` variant.
+    /// Use [Balance::try_from_vector_in] for a non-panicking conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Convention};
+    ///
+    /// assert_eq!(Balance::from_vector_in(1, 1, Convention::MathYUp), Balance::TopRight);
+    /// assert_eq!(Balance::from_vector_in(1, 1, Convention::ScreenYDown), Balance::BottomRight);
+    /// ```
+    pub const fn from_vector_in(a: i8, b: i8, convention: Convention) -> Self {
+        match Self::try_from_vector_in(a, b, convention) {
+            Ok(balance) => balance,
+            Err(_) => panic!("Invalid vector"),
+        }
+    }
+
+    /// Converts a pair of integers `(a, b)` expressed in the given `Convention` into the
+    /// corresponding `Balance` variant without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidVector] if one of the coordinates is not in the range `-1..=1`.
+    pub const fn try_from_vector_in(
+        a: i8,
+        b: i8,
+        convention: Convention,
+    ) -> Result<Self, BalanceError> {
+        match convention {
+            Convention::ScreenYDown => Self::try_from_vector(a, b),
+            Convention::MathYUp | Convention::CompassBearing => {
+                match Self::try_from_vector(a, b.wrapping_neg()) {
+                    Ok(balance) => Ok(balance),
+                    Err(_) => Err(BalanceError::InvalidVector(a, b)),
+                }
+            }
+        }
+    }
+
+    /// Converts the current `Balance` position into its angle in degrees in the given `Convention`.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `Balance::Center`. Use [Balance::try_to_angle_in] for a non-panicking conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Convention};
+    ///
+    /// assert_eq!(Balance::Bottom.to_angle_in(Convention::ScreenYDown), 90.0);
+    /// assert_eq!(Balance::Bottom.to_angle_in(Convention::MathYUp), -90.0);
+    /// assert_eq!(Balance::Bottom.to_angle_in(Convention::CompassBearing), 180.0);
+    /// assert_eq!(Balance::Left.to_angle_in(Convention::ScreenYDown), 180.0);
+    /// ```
+    pub const fn to_angle_in(self, convention: Convention) -> f64 {
+        match self.try_to_angle_in(convention) {
+            Ok(angle) => angle,
+            Err(_) => panic!("Invalid value: cannot convert Balance::Center to an angle."),
+        }
+    }
+
+    /// Converts the current `Balance` position into its angle in degrees in the given `Convention`
+    /// without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CenterAngle] for `Balance::Center`, which has no direction.
+    pub const fn try_to_angle_in(self, convention: Convention) -> Result<f64, BalanceError> {
+        let angle = match self.try_to_angle() {
+            Ok(angle) => angle,
+            Err(error) => return Err(error),
+        };
+        Ok(match convention {
+            Convention::ScreenYDown => {
+                if angle == Self::WEST {
+                    angle
+                } else {
+                    0.0 - angle
+                }
+            }
+            Convention::MathYUp => angle,
+            Convention::CompassBearing => {
+                let bearing = Self::NORTH - angle;
+                if bearing < 0.0 {
+                    bearing + 360.0
+                } else {
+                    bearing
+                }
+            }
+        })
+    }
+
+    /// Constructs a `Balance` enum variant based on the given angle in degrees in the given `Convention`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the angle is not exactly one of the eight directions.
+    /// Use [Balance::try_from_angle_in] for a non-panicking conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Convention};
+    ///
+    /// assert_eq!(Balance::from_angle_in(45.0, Convention::ScreenYDown), Balance::BottomRight);
+    /// assert_eq!(Balance::from_angle_in(45.0, Convention::MathYUp), Balance::TopRight);
+    /// assert_eq!(Balance::from_angle_in(270.0, Convention::CompassBearing), Balance::Left);
+    /// ```
+    pub const fn from_angle_in(angle: f64, convention: Convention) -> Self {
+        match Self::try_from_angle_in(angle, convention) {
+            Ok(balance) => balance,
+            Err(_) => {
+                panic!("Invalid angle. Cannot construct a Balance from an approximate angle.")
+            }
+        }
+    }
+
+    /// Constructs a `Balance` enum variant based on the given angle in degrees in the given `Convention`
+    /// without panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidAngle] if the angle is not exactly one of the eight directions.
+    pub const fn try_from_angle_in(
+        angle: f64,
+        convention: Convention,
+    ) -> Result<Self, BalanceError> {
+        let math_angle = match convention {
+            Convention::ScreenYDown => 0.0 - angle,
+            Convention::MathYUp => angle,
+            Convention::CompassBearing => Self::NORTH - angle,
+        };
+        match Self::try_from_angle(math_angle) {
+            Ok(balance) => Ok(balance),
+            Err(_) => Err(BalanceError::InvalidAngle(angle)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Convention};
+
+    const CONVENTIONS: [Convention; 3] = [
+        Convention::ScreenYDown,
+        Convention::MathYUp,
+        Convention::CompassBearing,
+    ];
+
+    const DIRECTIONS: [Balance; 8] = [
+        Balance::TopLeft,
+        Balance::Top,
+        Balance::TopRight,
+        Balance::Left,
+        Balance::Right,
+        Balance::BottomLeft,
+        Balance::Bottom,
+        Balance::BottomRight,
+    ];
+
+    #[test]
+    fn test_round_trips() {
+        for convention in CONVENTIONS {
+            for balance in DIRECTIONS {
+                let (x, y) = balance.to_vector_in(convention);
+                assert_eq!(Balance::from_vector_in(x, y, convention), balance);
+                let angle = balance.to_angle_in(convention);
+                assert_eq!(Balance::from_angle_in(angle, convention), balance);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compass_bearings() {
+        assert_eq!(
+            DIRECTIONS.map(|b| b.to_angle_in(Convention::CompassBearing)),
+            [315.0, 0.0, 45.0, 270.0, 90.0, 225.0, 180.0, 135.0]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Balance;
+use crate::{Balance, BalanceError};
 
 impl Balance {
     pub const EAST: f64 = 0.0;
@@ -73,6 +73,7 @@ impl Balance {
     /// # Panics
     ///
     /// This function will panic if the input value is not in the range `-4..=4`.
+    /// Use [Balance::try_from_value] for a non-panicking conversion.
     ///
     /// # Examples
     ///
@@ -89,7 +90,30 @@ impl Balance {
     /// // let invalid = Balance::from_value(5);
     /// ```
     pub const fn from_value(value: i8) -> Self {
-        match value {
+        match Self::try_from_value(value) {
+            Ok(balance) => balance,
+            Err(_) => panic!("Invalid value"),
+        }
+    }
+
+    /// Constructs a `Balance` variant from a given `i8` value without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::from_value].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidValue] if the input value is not in the range `-4..=4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::try_from_value(-4), Ok(Balance::TopLeft));
+    /// assert_eq!(Balance::try_from_value(5), Err(BalanceError::InvalidValue(5)));
+    /// ```
+    pub const fn try_from_value(value: i8) -> Result<Self, BalanceError> {
+        Ok(match value {
             -4 => Balance::TopLeft,
             -3 => Balance::Top,
             -2 => Balance::TopRight,
@@ -99,7 +123,71 @@ impl Balance {
             2 => Balance::BottomLeft,
             3 => Balance::Bottom,
             4 => Balance::BottomRight,
-            _ => panic!("Invalid value"),
+            _ => return Err(BalanceError::InvalidValue(value)),
+        })
+    }
+
+    /// Returns the index of the current `Balance` in a nine-slot table, in the range `0..9`.
+    ///
+    /// The index is `to_value() + 4`: the cells are numbered row by row, from `Balance::TopLeft`
+    /// to `Balance::BottomRight`, as in [Balance::ALL].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::TopLeft.index(), 0);
+    /// assert_eq!(Balance::Center.index(), 4);
+    /// assert_eq!(Balance::BottomRight.index(), 8);
+    /// ```
+    pub const fn index(self) -> usize {
+        (self.to_value() + 4) as usize
+    }
+
+    /// Constructs a `Balance` variant from its index in a nine-slot table (see [Balance::index]).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the index is not in the range `0..9`.
+    /// Use [Balance::try_from_index] for a non-panicking conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_index(1), Balance::Top);
+    /// assert_eq!(Balance::from_index(Balance::Left.index()), Balance::Left);
+    /// ```
+    pub const fn from_index(index: usize) -> Self {
+        match Self::try_from_index(index) {
+            Ok(balance) => balance,
+            Err(_) => panic!("Invalid index"),
+        }
+    }
+
+    /// Constructs a `Balance` variant from its index in a nine-slot table without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::from_index].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidIndex] if the index is not in the range `0..9`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::try_from_index(8), Ok(Balance::BottomRight));
+    /// assert_eq!(Balance::try_from_index(9), Err(BalanceError::InvalidIndex(9)));
+    /// ```
+    pub const fn try_from_index(index: usize) -> Result<Self, BalanceError> {
+        if index < 9 {
+            Ok(Self::ALL[index])
+        } else {
+            Err(BalanceError::InvalidIndex(index))
         }
     }
 
@@ -169,6 +257,10 @@ impl Balance {
     ///
     /// A `f64` value representing the angle in degrees.
     ///
+    /// # Panics
+    ///
+    /// Panics for `Balance::Center`. Use [Balance::try_to_angle] for a non-panicking conversion.
+    ///
     /// # Examples
     ///
     /// ```
@@ -178,7 +270,31 @@ impl Balance {
     /// assert_eq!(position.to_angle(), 90.0);
     /// ```
     pub const fn to_angle(self) -> f64 {
-        match self {
+        match self.try_to_angle() {
+            Ok(angle) => angle,
+            Err(_) => panic!("Invalid value: cannot convert Balance::Center to an angle."),
+        }
+    }
+
+    /// Converts the current `Balance` position into its corresponding
+    /// angle in degrees without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::to_angle].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CenterAngle] for `Balance::Center`, which has no direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::Top.try_to_angle(), Ok(90.0));
+    /// assert_eq!(Balance::Center.try_to_angle(), Err(BalanceError::CenterAngle));
+    /// ```
+    pub const fn try_to_angle(self) -> Result<f64, BalanceError> {
+        Ok(match self {
             Balance::TopLeft => Self::NORTH_WEST,
             Balance::Top => Self::NORTH,
             Balance::TopRight => Self::NORTH_EAST,
@@ -187,8 +303,8 @@ impl Balance {
             Balance::BottomLeft => Self::SOUTH_WEST,
             Balance::Bottom => Self::SOUTH,
             Balance::BottomRight => Self::SOUTH_EAST,
-            _ => panic!("Invalid value: cannot convert Balance::Center to an angle."),
-        }
+            Balance::Center => return Err(BalanceError::CenterAngle),
+        })
     }
 
     /// Constructs a `Balance` enum variant based on the given angle in degrees.
@@ -201,6 +317,11 @@ impl Balance {
     ///
     /// A `Balance` enum variant corresponding to the direction indicated by the angle.
     ///
+    /// # Panics
+    ///
+    /// Panics if the angle is not exactly one of the eight directions.
+    /// Use [Balance::try_from_angle] for a non-panicking conversion.
+    ///
     /// # Examples
     ///
     /// ```
@@ -216,11 +337,39 @@ impl Balance {
     /// assert_eq!(balance, Balance::Bottom);
     /// ```
     pub const fn from_angle(angle: f64) -> Self {
-        let mut angle = angle % 360.0;
-        if angle > 180.0 {
-            angle = -(360.0 - angle);
+        match Self::try_from_angle(angle) {
+            Ok(balance) => balance,
+            Err(_) => {
+                panic!("Invalid angle. Cannot construct a Balance from an approximate angle.")
+            }
+        }
+    }
+
+    /// Constructs a `Balance` enum variant based on the given angle in degrees without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::from_angle].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidAngle] if the angle is not exactly one of the eight directions
+    /// (modulo 360 degrees).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::try_from_angle(270.0), Ok(Balance::Bottom));
+    /// assert_eq!(Balance::try_from_angle(10.0), Err(BalanceError::InvalidAngle(10.0)));
+    /// ```
+    pub const fn try_from_angle(angle: f64) -> Result<Self, BalanceError> {
+        let mut normalized = angle % 360.0;
+        if normalized > 180.0 {
+            normalized = -(360.0 - normalized);
+        } else if normalized <= -180.0 {
+            normalized += 360.0;
         }
-        match angle {
+        Ok(match normalized {
             Self::EAST => Balance::Right,
             Self::NORTH_EAST => Balance::TopRight,
             Self::NORTH => Balance::Top,
@@ -229,8 +378,171 @@ impl Balance {
             Self::SOUTH_WEST => Balance::BottomLeft,
             Self::SOUTH => Balance::Bottom,
             Self::SOUTH_EAST => Balance::BottomRight,
-            _ => panic!("Invalid angle. Cannot construct a Balance from an approximate angle."),
+            _ => return Err(BalanceError::InvalidAngle(angle)),
+        })
+    }
+
+    /// Constructs the `Balance` variant whose direction is the nearest to the given angle in degrees.
+    ///
+    /// Unlike [Balance::from_angle], any angle is accepted: the circle is divided into eight
+    /// sectors of 45 degrees centered on `EAST`, `NORTH_EAST`, `NORTH`, ... and the angle snaps
+    /// to the direction of the sector containing it. An angle lying exactly on a sector boundary
+    /// snaps to the counterclockwise neighbour.
+    ///
+    /// # Parameters
+    ///
+    /// - `angle`: A `f64` value representing the angle in degrees (math convention, counterclockwise).
+    ///
+    /// # Returns
+    ///
+    /// A `Balance` enum variant other than `Balance::Center`, or `Balance::Center` if the
+    /// angle is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_angle_nearest(10.0), Balance::Right);
+    /// assert_eq!(Balance::from_angle_nearest(30.0), Balance::TopRight);
+    /// assert_eq!(Balance::from_angle_nearest(-100.0), Balance::Bottom);
+    /// assert_eq!(Balance::from_angle_nearest(530.0), Balance::Left);
+    /// assert_eq!(Balance::from_angle_nearest(f64::NAN), Balance::Center);
+    /// ```
+    pub const fn from_angle_nearest(angle: f64) -> Self {
+        if !angle.is_finite() {
+            return Balance::Center;
+        }
+        let mut normalized = angle % 360.0;
+        if normalized < 0.0 {
+            normalized += 360.0;
+        }
+        match ((normalized + 22.5) / 45.0) as u8 % 8 {
+            0 => Balance::Right,
+            1 => Balance::TopRight,
+            2 => Balance::Top,
+            3 => Balance::TopLeft,
+            4 => Balance::Left,
+            5 => Balance::BottomLeft,
+            6 => Balance::Bottom,
+            _ => Balance::BottomRight,
+        }
+    }
+
+    /// Constructs the `Balance` variant whose direction is the nearest to the given angle in radians.
+    ///
+    /// See [Balance::from_angle_nearest].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::f64::consts::{FRAC_PI_2, PI};
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_radians_nearest(FRAC_PI_2), Balance::Top);
+    /// assert_eq!(Balance::from_radians_nearest(-3.0 * PI / 4.0 + 0.1), Balance::BottomLeft);
+    /// ```
+    pub const fn from_radians_nearest(angle: f64) -> Self {
+        Self::from_angle_nearest(angle.to_degrees())
+    }
+
+    /// Constructs the `Balance` variant whose direction is the nearest to the given `f64` vector.
+    ///
+    /// The vector uses the same coordinates as [Balance::to_vector] (`y` grows downward).
+    /// Each of the eight directions covers a sector of 45 degrees, so the result is the
+    /// same as [Balance::from_angle_nearest] on the angle of the vector, but computed
+    /// without trigonometry. The ties differ: a vector lying exactly on a sector boundary
+    /// snaps to the diagonal one of the two directions it separates.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: The x-component of the vector.
+    /// - `y`: The y-component of the vector.
+    /// - `threshold`: The length at or below which the vector is considered null.
+    ///
+    /// # Returns
+    ///
+    /// `Balance::Center` if the vector length is lower than or equal to `threshold`
+    /// (or if a component is not finite), the nearest direction otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_f64_vector(3.0, -0.5, 0.0), Balance::Right);
+    /// assert_eq!(Balance::from_f64_vector(0.7, -0.6, 0.0), Balance::TopRight);
+    /// assert_eq!(Balance::from_f64_vector(0.05, 0.05, 0.1), Balance::Center);
+    ///
+    /// // Exactly on the 22.5 and 67.5 degree boundaries.
+    /// let tan = core::f64::consts::SQRT_2 - 1.0;
+    /// assert_eq!(Balance::from_f64_vector(1.0, -tan, 0.0), Balance::TopRight);
+    /// assert_eq!(Balance::from_f64_vector(tan, -1.0, 0.0), Balance::TopRight);
+    /// assert_eq!(Balance::from_f64_vector(-tan, 1.0, 0.0), Balance::BottomLeft);
+    /// ```
+    pub const fn from_f64_vector(x: f64, y: f64, threshold: f64) -> Self {
+        // tan(22.5°): the slope of the boundary between an edge and a corner sector.
+        const TAN_22_5: f64 = core::f64::consts::SQRT_2 - 1.0;
+        if !x.is_finite() || !y.is_finite() || x * x + y * y <= threshold * threshold {
+            return Balance::Center;
         }
+        let (ax, ay) = (x.abs(), y.abs());
+        let a = if ax < ay * TAN_22_5 {
+            0
+        } else if x > 0.0 {
+            1
+        } else {
+            -1
+        };
+        let b = if ay < ax * TAN_22_5 {
+            0
+        } else if y > 0.0 {
+            1
+        } else {
+            -1
+        };
+        Self::from_vector(a, b)
+    }
+
+    /// Constructs the `Balance` variant whose direction is the nearest to the given `f32` vector.
+    ///
+    /// See [Balance::from_f64_vector].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::from_f32_vector(-0.2, 4.0, 0.5), Balance::Bottom);
+    /// assert_eq!(Balance::from_f32_vector(0.0, 0.0, 0.0), Balance::Center);
+    /// ```
+    pub const fn from_f32_vector(x: f32, y: f32, threshold: f32) -> Self {
+        Self::from_f64_vector(x as f64, y as f64, threshold as f64)
+    }
+
+    /// Converts the current `Balance` variant into a normalized 2D `f64` vector.
+    ///
+    /// The vector uses the same coordinates as [Balance::to_vector] (`y` grows downward)
+    /// and has a length of `1.0`, except for `Balance::Center` which is `(0.0, 0.0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::Top.to_unit_vector(), (0.0, -1.0));
+    /// assert_eq!(Balance::BottomRight.to_unit_vector(), (FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+    /// assert_eq!(Balance::Center.to_unit_vector(), (0.0, 0.0));
+    /// ```
+    pub const fn to_unit_vector(self) -> (f64, f64) {
+        let (x, y) = self.to_vector();
+        let scale = if self.is_corner() {
+            core::f64::consts::FRAC_1_SQRT_2
+        } else {
+            1.0
+        };
+        (x as f64 * scale, y as f64 * scale)
     }
 
     /// Converts the current `Balance` variant into a 2D vector `(i8, i8)` representing its coordinates.
@@ -268,6 +580,7 @@ impl Balance {
     /// # Panics
     ///
     /// Panics if the provided `(a, b)` pair does not correspond to a valid `Balance` variant.
+    /// Use [Balance::try_from_vector] for a non-panicking conversion.
     ///
     /// # Examples
     ///
@@ -281,7 +594,30 @@ impl Balance {
     /// assert_eq!(balance, Balance::Bottom);
     /// ```
     pub const fn from_vector(a: i8, b: i8) -> Self {
-        match (a, b) {
+        match Self::try_from_vector(a, b) {
+            Ok(balance) => balance,
+            Err(_) => panic!("Invalid vector"),
+        }
+    }
+
+    /// Converts a pair of integers `(a, b)` into the corresponding `Balance` variant without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::from_vector].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidVector] if one of the coordinates is not in the range `-1..=1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::try_from_vector(0, 1), Ok(Balance::Bottom));
+    /// assert_eq!(Balance::try_from_vector(2, 0), Err(BalanceError::InvalidVector(2, 0)));
+    /// ```
+    pub const fn try_from_vector(a: i8, b: i8) -> Result<Self, BalanceError> {
+        Ok(match (a, b) {
             (-1, -1) => Balance::TopLeft,
             (0, -1) => Balance::Top,
             (1, -1) => Balance::TopRight,
@@ -291,7 +627,31 @@ impl Balance {
             (-1, 1) => Balance::BottomLeft,
             (0, 1) => Balance::Bottom,
             (1, 1) => Balance::BottomRight,
-            _ => panic!("Invalid vector"),
-        }
+            _ => return Err(BalanceError::InvalidVector(a, b)),
+        })
+    }
+}
+
+impl TryFrom<i8> for Balance {
+    type Error = BalanceError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        Self::try_from_value(value)
+    }
+}
+
+impl TryFrom<(i8, i8)> for Balance {
+    type Error = BalanceError;
+
+    fn try_from((a, b): (i8, i8)) -> Result<Self, Self::Error> {
+        Self::try_from_vector(a, b)
+    }
+}
+
+impl TryFrom<f64> for Balance {
+    type Error = BalanceError;
+
+    fn try_from(angle: f64) -> Result<Self, Self::Error> {
+        Self::try_from_angle(angle)
     }
 }
@@ -0,0 +1,89 @@
+use crate::Balance;
+use core::fmt::{Display, Formatter};
+
+/// Describes why a value could not be converted into (or from) a `Balance`.
+///
+/// Every panicking conversion of the crate has a fallible `try_*` counterpart
+/// returning this error instead of panicking, so that untrusted inputs can be
+/// handled gracefully.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, BalanceError};
+///
+/// assert_eq!(Balance::try_from_value(5), Err(BalanceError::InvalidValue(5)));
+/// assert_eq!(Balance::try_from_value(-4), Ok(Balance::TopLeft));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BalanceError {
+    /// The integer is not in the range `-4..=4` (see `Balance::to_value`).
+    InvalidValue(i8),
+    /// The index is not in the range `0..9` (see `Balance::index`).
+    InvalidIndex(usize),
+    /// One of the coordinates is not in the range `-1..=1`.
+    InvalidVector(i8, i8),
+    /// The angle (in degrees) is not one of the eight exact directions.
+    InvalidAngle(f64),
+    /// `Balance::Center` has no direction, thus no angle.
+    CenterAngle,
+    /// The logical state (or the coordinate) is neither certainly true nor certainly false.
+    Uncertain,
+    /// The string is not a known representation of a `Balance` (see `Notation`).
+    InvalidSymbol,
+    /// The movements do not fit in the capacity of an `ArrayPath`.
+    CapacityExceeded(usize),
+    /// Two paths expected to have the same length do not (see `Path::try_each_zip`).
+    LengthMismatch(usize, usize),
+    /// The code is not in the range of the chain code (see `ChainCode::decode`).
+    InvalidCode(u8),
+    /// The movement has no code in the chain code (see `ChainCode::encode`).
+    InvalidMovement(Balance),
+    /// The bytes are not a supported image (see `Bitmap::from_pbm` and `Bitmap::from_pgm`).
+    InvalidImage,
+}
+
+impl Display for BalanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BalanceError::InvalidValue(value) => {
+                write!(f, "invalid value {}: expected a value in -4..=4", value)
+            }
+            BalanceError::InvalidIndex(index) => {
+                write!(f, "invalid index {}: expected an index in 0..9", index)
+            }
+            BalanceError::InvalidVector(x, y) => write!(
+                f,
+                "invalid vector ({}, {}): expected coordinates in -1..=1",
+                x, y
+            ),
+            BalanceError::InvalidAngle(angle) => write!(
+                f,
+                "invalid angle {}: expected a multiple of 45 degrees",
+                angle
+            ),
+            BalanceError::CenterAngle => write!(f, "Balance::Center has no angle"),
+            BalanceError::Uncertain => {
+                write!(f, "an uncertain Balance cannot be converted to a boolean")
+            }
+            BalanceError::InvalidSymbol => write!(f, "unknown Balance symbol"),
+            BalanceError::CapacityExceeded(capacity) => {
+                write!(
+                    f,
+                    "capacity exceeded: expected at most {} movements",
+                    capacity
+                )
+            }
+            BalanceError::LengthMismatch(a, b) => {
+                write!(f, "length mismatch: {} movements against {}", a, b)
+            }
+            BalanceError::InvalidCode(code) => write!(f, "invalid chain code {}", code),
+            BalanceError::InvalidMovement(movement) => {
+                write!(f, "the movement {:?} has no chain code", movement)
+            }
+            BalanceError::InvalidImage => write!(f, "invalid or unsupported image"),
+        }
+    }
+}
+
+impl core::error::Error for BalanceError {}
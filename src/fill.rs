@@ -0,0 +1,280 @@
+use crate::{Bitmap, Connectivity, Coordinate, Path, Position};
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Selects which cells a closed `Path` encloses when it crosses itself or goes around an
+/// area several times (see [Path::interior_cells_with_rule]).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, FillRule, Path, Position};
+///
+/// // A 2x2 square outline, gone around twice.
+/// let mut movements = vec![Balance::Right, Balance::Right, Balance::Bottom, Balance::Bottom];
+/// movements.extend([Balance::Left, Balance::Left, Balance::Top, Balance::Top]);
+/// let twice = Path::new(movements.repeat(2));
+///
+/// let center = Position::new(1, 1);
+/// assert!(!twice.interior_cells_with_rule(Position::ORIGIN, FillRule::EvenOdd).contains(&center));
+/// assert!(twice.interior_cells_with_rule(Position::ORIGIN, FillRule::NonZero).contains(&center));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum FillRule {
+    /// A cell is inside if a ray from it crosses the route an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A cell is inside if the route winds around it (see [Path::winding_number]).
+    NonZero,
+}
+
+impl FillRule {
+    /// Checks whether a cell is inside, given the crossings counted from the left of its row.
+    const fn is_inside(self, winding: i64) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+impl Path {
+    /// Returns the cells enclosed by the `Path` going from `start`, its own cells included,
+    /// with the [FillRule::EvenOdd] rule.
+    ///
+    /// See [Path::interior_cells_with_rule].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let diamond = Path::new(vec![
+    ///     Balance::BottomRight,
+    ///     Balance::BottomLeft,
+    ///     Balance::TopLeft,
+    ///     Balance::TopRight,
+    /// ]);
+    /// assert_eq!(
+    ///     diamond.interior_cells(Position::new(0, 0)),
+    ///     [
+    ///         Position::new(0, 0),
+    ///         Position::new(-1, 1),
+    ///         Position::new(0, 1),
+    ///         Position::new(1, 1),
+    ///         Position::new(0, 2),
+    ///     ]
+    /// );
+    /// ```
+    pub fn interior_cells<T: Coordinate>(&self, start: Position<T>) -> Vec<Position<T>> {
+        self.interior_cells_with_rule(start, FillRule::EvenOdd)
+    }
+
+    /// Returns the cells enclosed by the `Path` going from `start`, its own cells included,
+    /// row by row from the top-left one.
+    ///
+    /// The cells are found by scanline filling: each row is crossed from the left, counting
+    /// the movements of the route going through it, and the cells are kept according to
+    /// `rule`. An open `Path` is closed by the [line](Path::line) from its end back to
+    /// `start`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a coordinate overflows `T`.
+    pub fn interior_cells_with_rule<T: Coordinate>(
+        &self,
+        start: Position<T>,
+        rule: FillRule,
+    ) -> Vec<Position<T>> {
+        let mut vertices: Vec<Position<T>> = self.positions(start).collect();
+        let end = vertices[vertices.len() - 1];
+        vertices.extend(Path::line(end, start).positions(end).skip(1));
+
+        // Each movement changing row crosses the upper of its two rows, at its upper cell,
+        // downwards (`1`) or upwards (`-1`).
+        let mut crossings: Vec<(T, T, i64)> = vertices
+            .windows(2)
+            .filter(|pair| pair[0].y != pair[1].y)
+            .map(|pair| {
+                let (upper, direction) = if pair[0].y < pair[1].y {
+                    (pair[0], 1)
+                } else {
+                    (pair[1], -1)
+                };
+                (upper.y, upper.x, direction)
+            })
+            .collect();
+        crossings.sort_unstable();
+
+        let one = T::from_i8(1);
+        let mut cells: Vec<Position<T>> = vertices;
+        let mut winding = 0;
+        for (i, &(y, x, direction)) in crossings.iter().enumerate() {
+            winding += direction;
+            match crossings.get(i + 1) {
+                Some(&(next_y, next_x, _)) if next_y == y => {
+                    if rule.is_inside(winding) {
+                        let mut x = x;
+                        while x < next_x {
+                            cells.push(Position::new(x, y));
+                            x = x + one;
+                        }
+                    }
+                }
+                // The route is closed: every row is left with no winding.
+                _ => winding = 0,
+            }
+        }
+        cells.sort_unstable_by_key(|cell| (cell.y, cell.x));
+        cells.dedup();
+        cells
+    }
+}
+
+impl Connectivity {
+    /// Returns the cells connected to `start` for which `inside` holds, `start` first.
+    ///
+    /// The cells are visited breadth first, thus ordered by their distance to `start`
+    /// (in movements of this `Connectivity`). The result is empty if `inside` does not hold
+    /// on `start`.
+    ///
+    /// The region must be bounded: `inside` must fail on all but a finite number of cells
+    /// connected to `start`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a coordinate overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Connectivity, Position};
+    ///
+    /// // The cells of a 3x3 square but its center.
+    /// let inside = |p: Position| p.x.abs() <= 1 && p.y.abs() <= 1 && (p.x, p.y) != (0, 0);
+    /// let start = Position::new(-1, 0);
+    ///
+    /// let four = Connectivity::Four.flood_fill(start, inside);
+    /// assert_eq!(four.len(), 8);
+    /// assert_eq!(four[0], start);
+    /// assert!(Connectivity::Eight.flood_fill(Position::new(0, 0), inside).is_empty());
+    /// ```
+    pub fn flood_fill<T: Coordinate>(
+        self,
+        start: Position<T>,
+        inside: impl Fn(Position<T>) -> bool,
+    ) -> Vec<Position<T>> {
+        if !inside(start) {
+            return Vec::new();
+        }
+        let mut visited = BTreeSet::from([start]);
+        let mut cells = vec![start];
+        let mut next = 0;
+        while let Some(&cell) = cells.get(next) {
+            next += 1;
+            for movement in self.neighbours() {
+                let neighbour = cell + movement;
+                if inside(neighbour) && visited.insert(neighbour) {
+                    cells.push(neighbour);
+                }
+            }
+        }
+        cells
+    }
+}
+
+impl Bitmap {
+    /// Returns a `Bitmap` of `width` x `height` cells, setting the cells connected to `start`
+    /// for which `inside` holds (see [Connectivity::flood_fill]).
+    ///
+    /// The fill does not go outside of the `Bitmap`, so that `inside` needs not bound the
+    /// region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Bitmap, Connectivity, Position};
+    ///
+    /// let walls = Bitmap::from_pbm(b"P1 4 3 0100 0110 1000").unwrap();
+    /// let free = |p| !walls.get(p);
+    ///
+    /// let four = Bitmap::flood_fill(4, 3, Position::new(0, 0), Connectivity::Four, free);
+    /// assert_eq!(four.positions().count(), 2);
+    /// let eight = Bitmap::flood_fill(4, 3, Position::new(0, 0), Connectivity::Eight, free);
+    /// assert_eq!(eight.positions().count(), 8);
+    /// ```
+    pub fn flood_fill(
+        width: usize,
+        height: usize,
+        start: Position,
+        connectivity: Connectivity,
+        inside: impl Fn(Position) -> bool,
+    ) -> Self {
+        let mut bitmap = Self::new(width, height);
+        let within =
+            |p: Position| p.x >= 0 && p.y >= 0 && (p.x as usize) < width && (p.y as usize) < height;
+        for cell in connectivity.flood_fill(start, |p| within(p) && inside(p)) {
+            bitmap.set(cell, true);
+        }
+        bitmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Bitmap, Connectivity, Contour, FillRule, Path, Position};
+    use alloc::vec;
+
+    #[test]
+    fn test_rectangle_interior() {
+        let mut movements = vec![Balance::Right; 3];
+        movements.extend([Balance::Bottom; 2]);
+        movements.extend([Balance::Left; 3]);
+        movements.extend([Balance::Top; 2]);
+        let path = Path::new(movements);
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            for outline in [&path, &path.inverse()] {
+                let cells = outline.interior_cells_with_rule(Position::new(10, -5), rule);
+                assert_eq!(cells.len(), 12);
+                assert_eq!(cells[0], Position::new(10, -5));
+                assert_eq!(cells[11], Position::new(13, -3));
+            }
+        }
+        // An open path is closed by a line back to its start.
+        let open = Path::new(vec![
+            Balance::Right,
+            Balance::Right,
+            Balance::Bottom,
+            Balance::Bottom,
+        ]);
+        assert_eq!(open.interior_cells(Position::new(0i8, 0)).len(), 6);
+        assert_eq!(
+            Path::default().interior_cells(Position::new(1, 2)),
+            [Position::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_contour_interiors() {
+        // The interior of the outer contour of a region without holes is the region.
+        let cells = [
+            false, true, true, false, false, //
+            true, true, true, true, false, //
+            false, true, true, true, true, //
+            false, false, true, false, false,
+        ];
+        let bitmap = Bitmap::from_cells(5, 4, cells.to_vec()).unwrap();
+        let contours = bitmap.contours(Connectivity::Eight);
+        assert_eq!(contours.len(), 1);
+        let mut expected: vec::Vec<Position> = bitmap.positions().collect();
+        expected.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(contours[0].path.interior_cells(contours[0].start), expected);
+
+        let start = bitmap.positions().next().unwrap();
+        let filled = Bitmap::flood_fill(5, 4, start, Connectivity::Four, |p| bitmap.get(p));
+        assert_eq!(filled, bitmap);
+        let square = Contour::trace_square(&cells, 5);
+        assert_eq!(square[0].path.interior_cells(square[0].start), expected);
+    }
+}
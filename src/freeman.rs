@@ -0,0 +1,427 @@
+use crate::{Balance, BalanceError, Connectivity, Orientation, Path, Turn};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Describes a Freeman chain code: how the movements of a `Path` are numbered.
+///
+/// A chain code numbers the directions from `0`, starting from a direction (`Balance::Right`
+/// by default) and going around in the given [Orientation] (counterclockwise by default):
+///
+/// - with [Connectivity::Eight], the codes `0..8` are 45 degrees apart (Freeman's code),
+/// - with [Connectivity::Four], the codes `0..4` are 90 degrees apart, and the diagonal
+///   movements have no code.
+///
+/// `Balance::Center` has no code.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, ChainCode, Orientation};
+///
+/// assert_eq!(ChainCode::FREEMAN.encode(Balance::Top), Ok(2));
+/// assert_eq!(ChainCode::FREEMAN_4.encode(Balance::Top), Ok(1));
+/// assert_eq!(ChainCode::FREEMAN.decode(7), Ok(Balance::BottomRight));
+///
+/// // Code 0 heading up, then clockwise.
+/// let compass = ChainCode::FREEMAN
+///     .with_start(Balance::Top)
+///     .with_orientation(Orientation::Clockwise);
+/// assert_eq!(compass.encode(Balance::Right), Ok(2));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct ChainCode {
+    connectivity: Connectivity,
+    start: Balance,
+    orientation: Orientation,
+}
+
+impl ChainCode {
+    /// The 8-connected Freeman chain code: `0` is `Balance::Right`, counterclockwise.
+    pub const FREEMAN: Self = Self::new(Connectivity::Eight);
+    /// The 4-connected Freeman chain code: `0` is `Balance::Right`, counterclockwise.
+    pub const FREEMAN_4: Self = Self::new(Connectivity::Four);
+
+    /// Creates a chain code of the given `Connectivity`, numbering the directions
+    /// counterclockwise from `Balance::Right`.
+    pub const fn new(connectivity: Connectivity) -> Self {
+        Self {
+            connectivity,
+            start: Balance::Right,
+            orientation: Orientation::Counterclockwise,
+        }
+    }
+
+    /// Returns the chain code with `start` as the direction of code `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is `Balance::Center`, which has no direction.
+    pub const fn with_start(self, start: Balance) -> Self {
+        if matches!(start, Balance::Center) {
+            panic!("Invalid start");
+        }
+        Self { start, ..self }
+    }
+
+    /// Returns the chain code numbering the directions in the given `Orientation`.
+    pub const fn with_orientation(self, orientation: Orientation) -> Self {
+        Self {
+            orientation,
+            ..self
+        }
+    }
+
+    /// Returns the `Connectivity` of the chain code.
+    pub const fn connectivity(self) -> Connectivity {
+        self.connectivity
+    }
+
+    /// Returns the direction of code `0`.
+    pub const fn start(self) -> Balance {
+        self.start
+    }
+
+    /// Returns the `Orientation` in which the codes increase.
+    pub const fn orientation(self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the number of codes: `4` or `8`.
+    pub const fn base(self) -> u8 {
+        self.connectivity.count()
+    }
+
+    /// Returns the code of `movement`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::CenterAngle] for `Balance::Center`, and
+    /// [BalanceError::InvalidMovement] for a movement between two codes (a diagonal
+    /// movement of a 4-connected chain code starting on an edge).
+    pub const fn encode(self, movement: Balance) -> Result<u8, BalanceError> {
+        let steps = match self.start.turn_to(movement) {
+            Some(turn) => turn.steps(),
+            None => return Err(BalanceError::CenterAngle),
+        };
+        let steps = match self.orientation {
+            Orientation::Counterclockwise => steps,
+            Orientation::Clockwise => (8 - steps) % 8,
+        };
+        let step = 8 / self.base();
+        if steps % step != 0 {
+            return Err(BalanceError::InvalidMovement(movement));
+        }
+        Ok(steps / step)
+    }
+
+    /// Returns the movement of `code`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidCode] if `code` is not less than [ChainCode::base].
+    pub const fn decode(self, code: u8) -> Result<Balance, BalanceError> {
+        if code >= self.base() {
+            return Err(BalanceError::InvalidCode(code));
+        }
+        let steps = (code * (8 / self.base())) as i8;
+        let steps = match self.orientation {
+            Orientation::Counterclockwise => steps,
+            Orientation::Clockwise => -steps,
+        };
+        Ok(self.start.rotate_by(Turn::from_steps(steps)))
+    }
+
+    /// Returns the digit of `code` in a chain code string.
+    const fn digit(code: u8) -> char {
+        (b'0' + code) as char
+    }
+}
+
+impl Default for ChainCode {
+    fn default() -> Self {
+        Self::FREEMAN
+    }
+}
+
+impl Path {
+    /// Creates a `Path` from a sequence of chain codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidCode] if a code is not less than [ChainCode::base].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError, ChainCode, Path};
+    ///
+    /// let path = Path::from_chain_code(&[0, 2, 4, 6], ChainCode::FREEMAN).unwrap();
+    /// assert_eq!(path[1], Balance::Top);
+    /// assert!(path.is_closed());
+    /// assert_eq!(
+    ///     Path::from_chain_code(&[0, 4], ChainCode::FREEMAN_4),
+    ///     Err(BalanceError::InvalidCode(4))
+    /// );
+    /// ```
+    pub fn from_chain_code(codes: &[u8], chain_code: ChainCode) -> Result<Self, BalanceError> {
+        codes
+            .iter()
+            .map(|&code| chain_code.decode(code))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Creates a `Path` from a string of chain code digits (`"0246"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidSymbol] if a character is not a decimal digit, and
+    /// [BalanceError::InvalidCode] if a digit is not less than [ChainCode::base].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, ChainCode, Path};
+    ///
+    /// let path = Path::from_chain_code_str("0123", ChainCode::FREEMAN_4).unwrap();
+    /// assert_eq!(path, Path::new(vec![Balance::Right, Balance::Top, Balance::Left, Balance::Bottom]));
+    /// assert!(Path::from_chain_code_str("01a", ChainCode::FREEMAN).is_err());
+    /// ```
+    pub fn from_chain_code_str(codes: &str, chain_code: ChainCode) -> Result<Self, BalanceError> {
+        codes
+            .chars()
+            .map(|digit| match digit.to_digit(10) {
+                Some(code) => chain_code.decode(code as u8),
+                None => Err(BalanceError::InvalidSymbol),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Returns the chain codes of the movements of the `Path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [ChainCode::encode] for the first movement which has no code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError, ChainCode, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Right, Balance::TopRight, Balance::Bottom]);
+    /// assert_eq!(path.to_chain_code(ChainCode::FREEMAN), Ok(vec![0, 1, 6]));
+    /// assert_eq!(
+    ///     path.to_chain_code(ChainCode::FREEMAN_4),
+    ///     Err(BalanceError::InvalidMovement(Balance::TopRight))
+    /// );
+    /// ```
+    pub fn to_chain_code(&self, chain_code: ChainCode) -> Result<Vec<u8>, BalanceError> {
+        self.raw
+            .iter()
+            .map(|&movement| chain_code.encode(movement))
+            .collect()
+    }
+
+    /// Returns the chain code digits of the movements of the `Path` as a string (`"0246"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [ChainCode::encode] for the first movement which has no code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ChainCode, Path};
+    ///
+    /// let path = Path::from_chain_code_str("7701", ChainCode::FREEMAN).unwrap();
+    /// assert_eq!(path.to_chain_code_string(ChainCode::FREEMAN).unwrap(), "7701");
+    /// ```
+    pub fn to_chain_code_string(&self, chain_code: ChainCode) -> Result<String, BalanceError> {
+        self.raw
+            .iter()
+            .map(|&movement| chain_code.encode(movement).map(ChainCode::digit))
+            .collect()
+    }
+
+    /// Returns the differential chain code of the `Path`: the number of steps of the chain
+    /// code between each movement and the previous one, modulo [ChainCode::base].
+    ///
+    /// The `Path` is taken as a closed boundary: the first difference is the one from the
+    /// last movement to the first. The differential chain code describes the turns of the
+    /// route, so it does not depend on the rotation of the shape by a multiple of the angle
+    /// between two codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [ChainCode::encode] for the first movement which has no code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ChainCode, Path};
+    ///
+    /// let path = Path::from_chain_code_str("10103322", ChainCode::FREEMAN_4).unwrap();
+    /// assert_eq!(path.differential_chain_code(ChainCode::FREEMAN_4), Ok(vec![3, 3, 1, 3, 3, 0, 3, 0]));
+    /// ```
+    pub fn differential_chain_code(&self, chain_code: ChainCode) -> Result<Vec<u8>, BalanceError> {
+        let codes = self.to_chain_code(chain_code)?;
+        let base = chain_code.base();
+        let previous = codes.iter().cycle().skip(codes.len().saturating_sub(1));
+        Ok(codes
+            .iter()
+            .zip(previous)
+            .map(|(&code, &previous)| (code + base - previous) % base)
+            .collect())
+    }
+
+    /// Creates a `Path` from its first movement and its differential chain code (see
+    /// [Path::differential_chain_code]).
+    ///
+    /// The first difference, from the last movement to the first one, is not needed to
+    /// rebuild the `Path`: only its validity is checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [ChainCode::encode] if `first` has no code, and
+    /// [BalanceError::InvalidCode] if a difference is not less than [ChainCode::base].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, ChainCode, Path};
+    ///
+    /// let path = Path::from_chain_code_str("0765", ChainCode::FREEMAN).unwrap();
+    /// let differences = path.differential_chain_code(ChainCode::FREEMAN).unwrap();
+    /// let rebuilt =
+    ///     Path::from_differential_chain_code(Balance::Right, &differences, ChainCode::FREEMAN);
+    /// assert_eq!(rebuilt, Ok(path));
+    /// ```
+    pub fn from_differential_chain_code(
+        first: Balance,
+        differences: &[u8],
+        chain_code: ChainCode,
+    ) -> Result<Self, BalanceError> {
+        let base = chain_code.base();
+        if let Some(&difference) = differences.iter().find(|&&d| d >= base) {
+            return Err(BalanceError::InvalidCode(difference));
+        }
+        let mut code = chain_code.encode(first)?;
+        let mut movements = Vec::with_capacity(differences.len());
+        for (i, &difference) in differences.iter().enumerate() {
+            if i > 0 {
+                code = (code + difference) % base;
+            }
+            movements.push(chain_code.decode(code)?);
+        }
+        Ok(Self::new(movements))
+    }
+
+    /// Returns the shape number of the `Path`: its differential chain code, rotated to be the
+    /// smallest in lexicographic order.
+    ///
+    /// The shape number of a closed boundary depends neither on the movement the route starts
+    /// with, nor on the rotation of the shape by a multiple of the angle between two codes:
+    /// two boundaries of the same shape have the same shape number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [ChainCode::encode] for the first movement which has no code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ChainCode, Path};
+    ///
+    /// let shape = Path::from_chain_code_str("00112233", ChainCode::FREEMAN_4).unwrap();
+    /// let rotated = Path::from_chain_code_str("23300112", ChainCode::FREEMAN_4).unwrap();
+    /// assert_eq!(
+    ///     shape.shape_number(ChainCode::FREEMAN_4),
+    ///     rotated.shape_number(ChainCode::FREEMAN_4)
+    /// );
+    /// assert_eq!(shape.shape_number(ChainCode::FREEMAN_4), Ok(vec![0, 1, 0, 1, 0, 1, 0, 1]));
+    /// ```
+    pub fn shape_number(&self, chain_code: ChainCode) -> Result<Vec<u8>, BalanceError> {
+        let mut differences = self.differential_chain_code(chain_code)?;
+        let start = minimal_rotation(&differences);
+        differences.rotate_left(start);
+        Ok(differences)
+    }
+}
+
+/// Returns the index at which the lexicographically smallest rotation of `codes` starts.
+fn minimal_rotation(codes: &[u8]) -> usize {
+    let n = codes.len();
+    let (mut i, mut j, mut k) = (0, 1, 0);
+    while i < n && j < n && k < n {
+        let (a, b) = (codes[(i + k) % n], codes[(j + k) % n]);
+        if a == b {
+            k += 1;
+            continue;
+        }
+        if a > b {
+            i += k + 1;
+        } else {
+            j += k + 1;
+        }
+        if i == j {
+            j += 1;
+        }
+        k = 0;
+    }
+    i.min(j)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, ChainCode, Connectivity, Orientation, Path};
+
+    #[test]
+    fn test_round_trips() {
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            for start in Balance::ALL.into_iter().filter(|&b| b != Balance::Center) {
+                for orientation in [Orientation::Counterclockwise, Orientation::Clockwise] {
+                    let chain_code = ChainCode::new(connectivity)
+                        .with_start(start)
+                        .with_orientation(orientation);
+                    let codes: Vec<u8> = (0..chain_code.base()).collect();
+                    let path = Path::from_chain_code(&codes, chain_code).unwrap();
+                    assert_eq!(path[0], start);
+                    assert_eq!(path.to_chain_code(chain_code), Ok(codes.clone()));
+                    // Successive codes turn by the same angle, in the chosen orientation.
+                    let turns: Vec<_> = path.windows(2).map(|w| w[0].turn_to(w[1])).collect();
+                    assert!(turns.windows(2).all(|w| w[0] == w[1]));
+                    assert_eq!(
+                        path[0].turn_to(path[1]).unwrap().signed_steps() > 0,
+                        orientation == Orientation::Counterclockwise
+                    );
+                    let differences = path.differential_chain_code(chain_code).unwrap();
+                    assert!(differences.iter().all(|&d| d == 1));
+                    assert_eq!(
+                        Path::from_differential_chain_code(start, &differences, chain_code),
+                        Ok(path)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shape_number_is_rotation_invariant() {
+        let shape = Path::from_chain_code_str("0007654432221", ChainCode::FREEMAN).unwrap();
+        let expected = shape.shape_number(ChainCode::FREEMAN).unwrap();
+        for turn in 0..8 {
+            let rotated = shape.each(|b| b.rotate_by(crate::Turn::from_steps(turn)));
+            for start in 0..rotated.len() {
+                let mut movements = rotated.to_vec();
+                movements.rotate_left(start);
+                let shifted = Path::new(movements);
+                assert_eq!(
+                    shifted.shape_number(ChainCode::FREEMAN),
+                    Ok(expected.clone())
+                );
+            }
+        }
+        assert_eq!(Path::default().shape_number(ChainCode::FREEMAN), Ok(vec![]));
+    }
+}
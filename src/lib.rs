@@ -42,19 +42,86 @@
 //! assert_eq!(balance.right(), Balance::Right);
 //! ```
 #![cfg_attr(not(test), no_std)]
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
+mod array_path;
 mod balance;
+mod binary;
+#[cfg(feature = "alloc")]
+mod bitmap;
+mod bindings;
+mod connectivity;
+#[cfg(feature = "alloc")]
+mod contour;
+mod convention;
 mod conversions;
+mod error;
+#[cfg(feature = "alloc")]
+mod fill;
+#[cfg(feature = "alloc")]
+mod freeman;
+#[cfg(feature = "alloc")]
+mod line;
+mod map;
+#[cfg(feature = "alloc")]
+mod metrics;
+#[cfg(feature = "alloc")]
+mod normalization;
+mod notation;
 mod operations;
 
 #[cfg(feature = "ternary")]
 mod ternary;
 
+#[cfg(feature = "alloc")]
 mod path;
+mod path_slice;
+mod position;
+#[cfg(feature = "alloc")]
+mod positions;
+#[cfg(feature = "serde")]
+pub mod serialization;
+mod set;
+mod steps;
+mod symmetry;
+mod turn;
+mod unary;
+mod zip;
 
+pub use array_path::ArrayPath;
 pub use balance::Balance;
+pub use binary::BinaryOp;
+#[cfg(feature = "alloc")]
+pub use bitmap::Bitmap;
+pub use bindings::KeyLayout;
+pub use connectivity::Connectivity;
+#[cfg(feature = "alloc")]
+pub use contour::Contour;
+pub use convention::Convention;
+pub use error::BalanceError;
+#[cfg(feature = "alloc")]
+pub use fill::FillRule;
+#[cfg(feature = "alloc")]
+pub use freeman::ChainCode;
+pub use map::{BalanceMap, BalanceMapIter, BalanceMapIterMut};
+#[cfg(feature = "alloc")]
+pub use metrics::Orientation;
+#[cfg(feature = "alloc")]
+pub use normalization::Normalization;
+pub use notation::Notation;
+#[cfg(feature = "alloc")]
 pub use path::Path;
+pub use path_slice::{MinimalSteps, PathSlice};
+pub use position::{Coordinate, Offset, Position};
+#[cfg(feature = "alloc")]
+pub use positions::IndexedPath;
+pub use set::{BalanceSet, BalanceSetIter};
+pub use steps::{Positions, ReduceBacktracks, Steps, ZipWith};
+pub use symmetry::Symmetry;
+pub use turn::Turn;
+pub use unary::UnaryOp;
+pub use zip::{PolicyZip, ZipPolicy};
 
 #[cfg(test)]
 mod tests {
@@ -67,8 +134,11 @@ mod tests {
     }
 
     mod readme_examples {
+        #[cfg(feature = "alloc")]
         use alloc::vec;
-        use crate::{Balance, Path};
+        use crate::Balance;
+        #[cfg(feature = "alloc")]
+        use crate::Path;
         use balanced_ternary::Digit;
 
         #[test]
@@ -85,6 +155,7 @@ mod tests {
         }
 
         #[test]
+        #[cfg(feature = "alloc")]
         fn path_example() {
             let movements = vec![Balance::Top, Balance::Right, Balance::Bottom];
             let path = Path::new(movements);
@@ -0,0 +1,169 @@
+use crate::{Balance, Coordinate, Path, Position};
+use alloc::vec::Vec;
+
+/// Returns the sign and the absolute value of a difference.
+const fn split(delta: i128) -> (i8, i128) {
+    (delta.signum() as i8, delta.abs())
+}
+
+impl Path {
+    /// Builds the 8-connected line going from `from` to `to` (Bresenham's algorithm).
+    ///
+    /// The line takes one step per unit of its major axis (`max(|dx|, |dy|)` steps), each
+    /// step moving along the major axis and, when the ideal line crosses to the next row
+    /// (or column), along the minor axis too. The diagonal steps are thus spread along
+    /// the line instead of being grouped as in [Path::from_vector].
+    ///
+    /// When the ideal line passes exactly between two cells, the cell with the smaller
+    /// minor coordinate (upper or left) is chosen. This rule does not depend on the
+    /// direction of the line: `Path::line(to, from)` visits the same cells as
+    /// `Path::line(from, to)`, in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let path = Path::line(Position::new(0, 0), Position::new(5, 2));
+    /// assert_eq!(
+    ///     path,
+    ///     Path::new(vec![
+    ///         Balance::Right,
+    ///         Balance::BottomRight,
+    ///         Balance::Right,
+    ///         Balance::BottomRight,
+    ///         Balance::Right,
+    ///     ])
+    /// );
+    /// assert_eq!(path.to_vector(), (5, 2));
+    /// ```
+    pub fn line<T: Coordinate>(from: Position<T>, to: Position<T>) -> Self {
+        let (sx, nx) = split(to.x.to_i128() - from.x.to_i128());
+        let (sy, ny) = split(to.y.to_i128() - from.y.to_i128());
+        let x_major = nx >= ny;
+        let (major, minor, minor_sign) = if x_major { (nx, ny, sy) } else { (ny, nx, sx) };
+        let mut movements = Vec::new();
+        // After `i` steps, the ideal minor offset `i * minor / major` is
+        // `quotient + remainder / major`.
+        let mut quotient = 0;
+        let mut remainder = 0;
+        let mut reached = 0;
+        for _ in 0..major {
+            remainder += minor;
+            if remainder >= major {
+                remainder -= major;
+                quotient += 1;
+            }
+            let rounded = if 2 * remainder > major || (2 * remainder == major && minor_sign < 0) {
+                quotient + 1
+            } else {
+                quotient
+            };
+            let side = if rounded > reached { minor_sign } else { 0 };
+            reached = rounded;
+            movements.push(if x_major {
+                Balance::from_vector(sx, side)
+            } else {
+                Balance::from_vector(side, sy)
+            });
+        }
+        Self::new(movements)
+    }
+
+    /// Builds the 4-connected line going from `from` to `to` (supercover line).
+    ///
+    /// The line only takes orthogonal steps (`|dx| + |dy|` steps), visiting the cells
+    /// crossed by the ideal line in the order of the crossings, so that no diagonal step
+    /// cuts a corner. This is the route to check for line-of-sight on a grid whose walls
+    /// block diagonal moves.
+    ///
+    /// When the ideal line passes exactly through the corner of two cells, the upper one
+    /// is visited. As with [Path::line], `Path::supercover_line(to, from)` visits the same
+    /// cells as `Path::supercover_line(from, to)`, in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let path = Path::supercover_line(Position::new(0, 0), Position::new(3, 1));
+    /// assert_eq!(
+    ///     path,
+    ///     Path::new(vec![Balance::Right, Balance::Right, Balance::Bottom, Balance::Right])
+    /// );
+    ///
+    /// // The exact diagonal crosses the corners: the upper cells are visited.
+    /// let path = Path::supercover_line(Position::new(0, 0), Position::new(2, 2));
+    /// assert_eq!(
+    ///     path,
+    ///     Path::new(vec![Balance::Right, Balance::Bottom, Balance::Right, Balance::Bottom])
+    /// );
+    /// ```
+    pub fn supercover_line<T: Coordinate>(from: Position<T>, to: Position<T>) -> Self {
+        let (sx, nx) = split(to.x.to_i128() - from.x.to_i128());
+        let (sy, ny) = split(to.y.to_i128() - from.y.to_i128());
+        let mut movements = Vec::new();
+        let (mut ix, mut iy) = (0, 0);
+        // The sign of `(1 + 2 * ix) * ny - (1 + 2 * iy) * nx` tells which of the next vertical
+        // (x) or horizontal (y) grid lines the ideal line crosses first.
+        let mut error = ny - nx;
+        while ix < nx || iy < ny {
+            let step_x = if ix == nx {
+                false
+            } else if iy == ny {
+                true
+            } else {
+                error < 0 || (error == 0 && sy > 0)
+            };
+            if step_x {
+                ix += 1;
+                error += 2 * ny;
+                movements.push(Balance::from_vector(sx, 0));
+            } else {
+                iy += 1;
+                error -= 2 * nx;
+                movements.push(Balance::from_vector(0, sy));
+            }
+        }
+        Self::new(movements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Path, Position};
+
+    /// Returns the cells visited by a path, starting at `start`.
+    fn cells(start: Position, path: &Path) -> Vec<Position> {
+        let mut position = start;
+        let mut cells = vec![position];
+        for &movement in path.iter() {
+            position += movement;
+            cells.push(position);
+        }
+        cells
+    }
+
+    #[test]
+    fn test_lines_are_symmetric() {
+        let from = Position::new(0, 0);
+        let lines: [fn(Position, Position) -> Path; 2] = [Path::line, Path::supercover_line];
+        for x in -7..=7 {
+            for y in -7..=7 {
+                let to = Position::new(x, y);
+                for line in lines {
+                    let forward = line(from, to);
+                    let mut backward = cells(to, &line(to, from));
+                    backward.reverse();
+                    assert_eq!(cells(from, &forward), backward, "{:?}", to);
+                    assert_eq!(forward.displacement(), to - from);
+                }
+                assert_eq!(Path::line(from, to).len() as i32, x.abs().max(y.abs()));
+                assert_eq!(
+                    Path::supercover_line(from, to).len() as i32,
+                    x.abs() + y.abs()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{Balance, Symmetry, Turn};
+use core::ops::{Index, IndexMut};
+
+/// A dense map holding one value for each of the nine `Balance` cells.
+///
+/// A `BalanceMap` replaces the arrays indexed with `to_value() + 4`: a sprite per facing,
+/// a cost per neighbour, the weights of a 3x3 kernel... It is indexed by `Balance`, and its
+/// values are stored in the order of [Balance::index].
+///
+/// The map can be rotated or mirrored as a whole ([BalanceMap::rotated_left],
+/// [BalanceMap::flipped_h], [BalanceMap::transformed], ...): the value of each cell moves
+/// to the image of the cell.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, BalanceMap};
+///
+/// let costs = BalanceMap::from_fn(|b: Balance| if b.is_corner() { 14 } else { 10 });
+/// assert_eq!(costs[Balance::TopLeft], 14);
+/// assert_eq!(costs[Balance::Left], 10);
+///
+/// let mut kernel = BalanceMap::new([0, 1, 0, 1, -4, 1, 0, 1, 0]);
+/// kernel[Balance::Center] = -8;
+/// assert_eq!(kernel.iter().map(|(_, &w)| w).sum::<i32>(), -4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub struct BalanceMap<T> {
+    values: [T; 9],
+}
+
+impl<T> BalanceMap<T> {
+    /// Creates a `BalanceMap` from its values, in the order of [Balance::ALL].
+    pub const fn new(values: [T; 9]) -> Self {
+        Self { values }
+    }
+
+    /// Creates a `BalanceMap` by calling `f` on each `Balance`, in the order of [Balance::ALL].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap};
+    ///
+    /// let vectors = BalanceMap::from_fn(Balance::to_vector);
+    /// assert_eq!(vectors[Balance::BottomLeft], (-1, 1));
+    /// ```
+    pub fn from_fn(f: impl FnMut(Balance) -> T) -> Self {
+        Self {
+            values: Balance::ALL.map(f),
+        }
+    }
+
+    /// Returns a reference to the value of the given `Balance`.
+    pub const fn get(&self, balance: Balance) -> &T {
+        &self.values[balance.index()]
+    }
+
+    /// Returns a mutable reference to the value of the given `Balance`.
+    pub fn get_mut(&mut self, balance: Balance) -> &mut T {
+        &mut self.values[balance.index()]
+    }
+
+    /// Returns the values, in the order of [Balance::ALL].
+    pub const fn as_array(&self) -> &[T; 9] {
+        &self.values
+    }
+
+    /// Consumes the map and returns its values, in the order of [Balance::ALL].
+    pub fn into_array(self) -> [T; 9] {
+        self.values
+    }
+
+    /// Returns an iterator over the `(Balance, &T)` pairs, in the order of [Balance::ALL].
+    pub fn iter(&self) -> BalanceMapIter<'_, T> {
+        Balance::ALL.into_iter().zip(self.values.iter())
+    }
+
+    /// Returns an iterator over the `(Balance, &mut T)` pairs, in the order of [Balance::ALL].
+    pub fn iter_mut(&mut self) -> BalanceMapIterMut<'_, T> {
+        Balance::ALL.into_iter().zip(self.values.iter_mut())
+    }
+
+    /// Returns a map with the result of `f` on each value.
+    ///
+    /// Use [BalanceMap::from_fn] when the result also depends on the `Balance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap};
+    ///
+    /// let weights = BalanceMap::from_fn(|b: Balance| b.to_scalar());
+    /// let halves = weights.map(|w| w as f64 / 2.0);
+    /// assert_eq!(halves[Balance::TopRight], 1.0);
+    /// ```
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> BalanceMap<U> {
+        BalanceMap {
+            values: self.values.map(f),
+        }
+    }
+
+    /// Returns a map pairing the values of this map with those of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap};
+    ///
+    /// let sprites = BalanceMap::new(["↖", "↑", "↗", "←", "·", "→", "↙", "↓", "↘"]);
+    /// let costs = BalanceMap::from_fn(|b: Balance| b.to_scalar());
+    /// let zipped = sprites.zip(costs);
+    /// assert_eq!(zipped[Balance::Bottom], ("↓", 1));
+    /// ```
+    pub fn zip<U>(self, other: BalanceMap<U>) -> BalanceMap<(T, U)> {
+        let mut others = other.values.into_iter();
+        self.map(|value| (value, others.next().unwrap()))
+    }
+
+    /// Returns a map where the value of `source(b)` is moved to each `b`.
+    ///
+    /// `source` must be a permutation of the nine cells.
+    fn permuted(self, source: impl Fn(Balance) -> Balance) -> Self {
+        let mut values = self.values.map(Some);
+        Self::from_fn(|balance| values[source(balance).index()].take().unwrap())
+    }
+
+    /// Returns the map rotated 90 degrees counterclockwise: the value of each `b` moves to
+    /// `b.rotate_left()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap};
+    ///
+    /// let map = BalanceMap::from_fn(|b: Balance| b.to_symbol());
+    /// let rotated = map.rotated_left();
+    /// assert_eq!(rotated[Balance::Top], Balance::Right.to_symbol());
+    /// assert_eq!(rotated[Balance::Center], Balance::Center.to_symbol());
+    /// ```
+    pub fn rotated_left(self) -> Self {
+        self.permuted(Balance::rotate_right)
+    }
+
+    /// Returns the map rotated 90 degrees clockwise: the value of each `b` moves to
+    /// `b.rotate_right()`.
+    pub fn rotated_right(self) -> Self {
+        self.permuted(Balance::rotate_left)
+    }
+
+    /// Returns the map rotated by a multiple of 45 degrees: the value of each direction `b`
+    /// moves to `b.rotate_by(turn)`, and the value of `Balance::Center` stays in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap, Turn};
+    ///
+    /// let map = BalanceMap::from_fn(|b: Balance| b.to_value());
+    /// let rotated = map.rotated_by(Turn::SlightRight);
+    /// assert_eq!(rotated[Balance::TopRight], Balance::Top.to_value());
+    /// ```
+    pub fn rotated_by(self, turn: Turn) -> Self {
+        self.permuted(|balance| balance.rotate_by(turn.inverse()))
+    }
+
+    /// Returns the map mirrored across the vertical axis: the value of each `b` moves to
+    /// `b.flip_h()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceMap};
+    ///
+    /// let map = BalanceMap::new([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(map.flipped_h().into_array(), [3, 2, 1, 6, 5, 4, 9, 8, 7]);
+    /// ```
+    pub fn flipped_h(self) -> Self {
+        self.permuted(Balance::flip_h)
+    }
+
+    /// Returns the map mirrored across the horizontal axis: the value of each `b` moves to
+    /// `b.flip_v()`.
+    pub fn flipped_v(self) -> Self {
+        self.permuted(Balance::flip_v)
+    }
+
+    /// Returns the map transformed by a `Symmetry`: the value of each `b` moves to
+    /// `symmetry.apply(b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{BalanceMap, Symmetry};
+    ///
+    /// let map = BalanceMap::new([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(map.transformed(Symmetry::Transpose).into_array(), [1, 4, 7, 2, 5, 8, 3, 6, 9]);
+    /// assert_eq!(map.transformed(Symmetry::RotateLeft), map.rotated_left());
+    /// ```
+    pub fn transformed(self, symmetry: Symmetry) -> Self {
+        let inverse = symmetry.inverse();
+        self.permuted(|balance| inverse.apply(balance))
+    }
+}
+
+/// An iterator over the `(Balance, &T)` pairs of a [BalanceMap].
+pub type BalanceMapIter<'a, T> =
+    core::iter::Zip<core::array::IntoIter<Balance, 9>, core::slice::Iter<'a, T>>;
+
+/// An iterator over the `(Balance, &mut T)` pairs of a [BalanceMap].
+pub type BalanceMapIterMut<'a, T> =
+    core::iter::Zip<core::array::IntoIter<Balance, 9>, core::slice::IterMut<'a, T>>;
+
+impl<T> Index<Balance> for BalanceMap<T> {
+    type Output = T;
+
+    fn index(&self, balance: Balance) -> &Self::Output {
+        self.get(balance)
+    }
+}
+
+impl<T> IndexMut<Balance> for BalanceMap<T> {
+    fn index_mut(&mut self, balance: Balance) -> &mut Self::Output {
+        self.get_mut(balance)
+    }
+}
+
+impl<T> From<[T; 9]> for BalanceMap<T> {
+    fn from(values: [T; 9]) -> Self {
+        Self::new(values)
+    }
+}
+
+impl<T> IntoIterator for BalanceMap<T> {
+    type Item = (Balance, T);
+    type IntoIter = core::iter::Zip<core::array::IntoIter<Balance, 9>, core::array::IntoIter<T, 9>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Balance::ALL.into_iter().zip(self.values)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BalanceMap<T> {
+    type Item = (Balance, &'a T);
+    type IntoIter = BalanceMapIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut BalanceMap<T> {
+    type Item = (Balance, &'a mut T);
+    type IntoIter = BalanceMapIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, BalanceMap, Symmetry};
+
+    #[test]
+    fn test_transformations_move_values() {
+        let map = BalanceMap::from_fn(|balance: Balance| balance);
+        for symmetry in Symmetry::ALL {
+            let transformed = map.transformed(symmetry);
+            for balance in Balance::ALL {
+                assert_eq!(transformed[symmetry.apply(balance)], balance);
+            }
+        }
+        for balance in Balance::ALL {
+            assert_eq!(map.rotated_left()[balance.rotate_left()], balance);
+            assert_eq!(map.rotated_right()[balance.rotate_right()], balance);
+            assert_eq!(map.flipped_v()[balance.flip_v()], balance);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{Balance, Coordinate, Offset, Path, Position};
+use alloc::collections::BTreeSet;
+use core::f64::consts::SQRT_2;
+
+/// The direction in which a closed `Path` goes around the area it encloses.
+///
+/// As with [Balance::rotate_left], counterclockwise is understood as seen on the grid,
+/// whose y-axis points down.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Orientation {
+    /// The enclosed area is on the left of the route.
+    Counterclockwise,
+    /// The enclosed area is on the right of the route.
+    Clockwise,
+}
+
+impl Path {
+    /// Returns the positions visited by the `Path` from `(0, 0)`, in mathematical coordinates
+    /// (y-axis pointing up), the start included.
+    fn vertices(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        let mut position = (0i64, 0i64);
+        core::iter::once(position).chain(self.raw.iter().map(move |movement| {
+            let (x, y) = movement.to_vector();
+            position = (position.0 + x as i64, position.1 - y as i64);
+            position
+        }))
+    }
+
+    /// Returns the number of movements which are not `Balance::Center`: the length of the
+    /// route when every step, straight or diagonal, counts for `1` (Chebyshev metric).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::Center, Balance::TopRight]);
+    /// assert_eq!(path.chebyshev_length(), 2);
+    /// ```
+    pub fn chebyshev_length(&self) -> usize {
+        self.raw.iter().filter(|&&b| b != Balance::Center).count()
+    }
+
+    /// Returns the length of the route when a diagonal step counts for `2` (Manhattan metric).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::Center, Balance::TopRight]);
+    /// assert_eq!(path.manhattan_length(), 3);
+    /// ```
+    pub fn manhattan_length(&self) -> usize {
+        self.raw
+            .iter()
+            .map(|movement| (movement.x().abs() + movement.y().abs()) as usize)
+            .sum()
+    }
+
+    /// Returns the length of the route when a diagonal step counts for `√2` (Euclidean metric).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::TopRight, Balance::TopRight]);
+    /// assert_eq!(path.euclidean_length(), 1.0 + 2.0 * core::f64::consts::SQRT_2);
+    /// ```
+    pub fn euclidean_length(&self) -> f64 {
+        let diagonals = self.raw.iter().filter(|b| b.is_corner()).count();
+        let straights = self.raw.iter().filter(|b| b.is_edge()).count();
+        straights as f64 + diagonals as f64 * SQRT_2
+    }
+
+    /// Returns the octile distance between the start and the end of the `Path`: the Euclidean
+    /// length of the shortest route with the same displacement, `max + (√2 - 1) * min` of
+    /// the absolute components.
+    ///
+    /// Unlike the `*_length` methods, it does not measure the route itself: a `Path` coming
+    /// back to its start has an octile distance of `0`. Comparing it to
+    /// [Path::euclidean_length] tells how much longer than necessary a route is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::Right, Balance::Right]);
+    /// assert_eq!(path.octile_distance(), 1.0 + core::f64::consts::SQRT_2);
+    /// assert!(path.octile_distance() < path.euclidean_length());
+    /// ```
+    pub fn octile_distance(&self) -> f64 {
+        let offset = self.displacement::<i64>();
+        let (x, y) = (offset.x.abs(), offset.y.abs());
+        let (max, min) = (x.max(y), x.min(y));
+        (max - min) as f64 + min as f64 * SQRT_2
+    }
+
+    /// Returns the smallest and the largest corners of the box containing every position
+    /// visited by the `Path` from [Position::ORIGIN].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a coordinate overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::TopRight, Balance::Bottom, Balance::Left]);
+    /// let (min, max) = path.bounding_box::<i32>();
+    /// assert_eq!(min, Position::new(0, -2));
+    /// assert_eq!(max, Position::new(1, 0));
+    /// ```
+    pub fn bounding_box<T: Coordinate>(&self) -> (Position<T>, Position<T>) {
+        let origin = Position::<T>::ORIGIN;
+        let (mut min, mut max) = (origin, origin);
+        for position in self.positions(origin) {
+            min = Position::new(min.x.min(position.x), min.y.min(position.y));
+            max = Position::new(max.x.max(position.x), max.y.max(position.y));
+        }
+        (min, max)
+    }
+
+    /// Checks whether the `Path` ends where it starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// assert!(Path::new(vec![Balance::Top, Balance::BottomRight, Balance::Left]).is_closed());
+    /// assert!(!Path::new(vec![Balance::Top]).is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.displacement::<i64>() == Offset::ZERO
+    }
+
+    /// Returns twice the signed area enclosed by the `Path` (shoelace formula).
+    fn double_signed_area(&self) -> i128 {
+        let mut vertices = self.vertices();
+        let mut previous = vertices.next().unwrap_or_default();
+        let mut sum = 0;
+        // The closing edge goes back to the start, at `(0, 0)`, and adds nothing to the sum.
+        for vertex in vertices {
+            sum += previous.0 as i128 * vertex.1 as i128 - vertex.0 as i128 * previous.1 as i128;
+            previous = vertex;
+        }
+        sum
+    }
+
+    /// Returns the signed area enclosed by the `Path`, computed with the shoelace formula over
+    /// the visited positions.
+    ///
+    /// The area is positive when the route goes counterclockwise, and negative when it goes
+    /// clockwise (see [Orientation]). An open `Path` is closed by a segment from its end
+    /// back to its start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// // Right, up, left, down: counterclockwise on the grid.
+    /// let square = Path::new(vec![Balance::Right, Balance::Top, Balance::Left, Balance::Bottom]);
+    /// assert_eq!(square.signed_area(), 1.0);
+    /// assert_eq!(square.inverse().signed_area(), -1.0);
+    ///
+    /// let triangle = Path::new(vec![Balance::Right, Balance::Right, Balance::TopLeft]);
+    /// assert_eq!(triangle.signed_area(), 1.0);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        self.double_signed_area() as f64 / 2.0
+    }
+
+    /// Returns the direction in which the `Path` goes around the area it encloses, or `None`
+    /// if its signed area is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Orientation, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Right, Balance::Bottom, Balance::Left, Balance::Top]);
+    /// assert_eq!(path.orientation(), Some(Orientation::Clockwise));
+    /// assert_eq!(Path::new(vec![Balance::Right, Balance::Left]).orientation(), None);
+    /// ```
+    pub fn orientation(&self) -> Option<Orientation> {
+        match self.double_signed_area() {
+            0 => None,
+            area if area > 0 => Some(Orientation::Counterclockwise),
+            _ => Some(Orientation::Clockwise),
+        }
+    }
+
+    /// Returns the number of times the `Path` winds around `point`, the `Path` starting at
+    /// [Position::ORIGIN].
+    ///
+    /// Counterclockwise turns count positively and clockwise turns negatively. An open `Path`
+    /// is closed by a segment from its end back to its start. The result is unspecified for
+    /// a point lying on the route.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let mut movements = vec![Balance::Right; 2];
+    /// movements.extend([Balance::Bottom; 2]);
+    /// movements.extend([Balance::Left; 2]);
+    /// movements.extend([Balance::Top; 2]);
+    /// let square = Path::new(movements);
+    /// assert_eq!(square.winding_number(Position::new(1, 1)), -1);
+    /// assert_eq!(square.winding_number(Position::new(3, 1)), 0);
+    /// ```
+    pub fn winding_number<T: Coordinate>(&self, point: Position<T>) -> i64 {
+        let (px, py) = (point.x.to_i128(), -point.y.to_i128());
+        let mut winding = 0;
+        let mut vertices = self.vertices().chain(core::iter::once((0, 0)));
+        let Some(mut previous) = vertices.next() else {
+            return 0;
+        };
+        for vertex in vertices {
+            let (ax, ay) = (previous.0 as i128, previous.1 as i128);
+            let (bx, by) = (vertex.0 as i128, vertex.1 as i128);
+            let side = (bx - ax) * (py - ay) - (px - ax) * (by - ay);
+            if ay <= py {
+                if by > py && side > 0 {
+                    winding += 1;
+                }
+            } else if by <= py && side < 0 {
+                winding -= 1;
+            }
+            previous = vertex;
+        }
+        winding
+    }
+
+    /// Checks whether the `Path` never visits a position twice.
+    ///
+    /// Coming back to the start with the last movement is allowed once at least three
+    /// positions have been visited, so that the outline of a simple polygon is self-avoiding.
+    /// A `Balance::Center` movement stays on the same position, thus revisits it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let square = Path::new(vec![Balance::Right, Balance::Top, Balance::Left, Balance::Bottom]);
+    /// assert!(square.is_self_avoiding());
+    ///
+    /// let mut eight = square.clone();
+    /// eight.push(Balance::Left);
+    /// eight.push(Balance::Bottom);
+    /// eight.push(Balance::Right);
+    /// eight.push(Balance::Top);
+    /// assert!(!eight.is_self_avoiding());
+    /// ```
+    pub fn is_self_avoiding(&self) -> bool {
+        let mut visited = BTreeSet::new();
+        let last = self.raw.len();
+        self.vertices().enumerate().all(|(i, vertex)| {
+            visited.insert(vertex) || (i == last && vertex == (0, 0) && visited.len() >= 3)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Orientation, Path, Position};
+
+    #[test]
+    fn test_rectangle_metrics() {
+        // A 3x2 rectangle outline, clockwise on the grid.
+        let mut movements = vec![Balance::Right; 3];
+        movements.extend([Balance::Bottom; 2]);
+        movements.extend([Balance::Left; 3]);
+        movements.extend([Balance::Top; 2]);
+        let path = Path::new(movements);
+        assert!(path.is_closed());
+        assert!(path.is_self_avoiding());
+        assert_eq!(path.signed_area(), -6.0);
+        assert_eq!(path.orientation(), Some(Orientation::Clockwise));
+        assert_eq!(
+            path.inverse().orientation(),
+            Some(Orientation::Counterclockwise)
+        );
+        assert_eq!(
+            path.bounding_box::<i8>(),
+            (Position::new(0, 0), Position::new(3, 2))
+        );
+        assert_eq!(path.manhattan_length(), 10);
+        assert_eq!(path.octile_distance(), 0.0);
+        for x in 1..3 {
+            assert_eq!(path.winding_number(Position::new(x, 1)), -1);
+            assert_eq!(path.inverse().winding_number(Position::new(x, 1)), 1);
+        }
+        assert_eq!(path.winding_number(Position::new(-1, 1)), 0);
+        assert_eq!(path.winding_number(Position::new(1, 5)), 0);
+
+        let mut twice = path.clone();
+        for &movement in path.iter() {
+            twice.push(movement);
+        }
+        assert_eq!(twice.winding_number(Position::new(1, 1)), -2);
+        assert!(!twice.is_self_avoiding());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Balance, Offset, Path, Position};
+use alloc::vec::Vec;
+
+/// Selects the shape of the `Path` built by [Path::normalized_with].
+///
+/// Every strategy builds a path with the same displacement as the original one, and of
+/// minimal length for its metric: `max(|dx|, |dy|)` steps (Chebyshev distance), except
+/// [Normalization::Orthogonal] which builds `|dx| + |dy|` steps (Manhattan distance).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Normalization, Path};
+///
+/// let path = Path::new(vec![Balance::Right, Balance::Right, Balance::Right, Balance::Bottom]);
+/// assert_eq!(
+///     path.normalized_with(Normalization::DiagonalsFirst),
+///     Path::new(vec![Balance::BottomRight, Balance::Right, Balance::Right])
+/// );
+/// assert_eq!(
+///     path.normalized_with(Normalization::StraightsFirst),
+///     Path::new(vec![Balance::Right, Balance::Right, Balance::BottomRight])
+/// );
+/// assert_eq!(
+///     path.normalized_with(Normalization::Interleaved),
+///     Path::new(vec![Balance::Right, Balance::BottomRight, Balance::Right])
+/// );
+/// assert_eq!(path.normalized_with(Normalization::Orthogonal).len(), 4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum Normalization {
+    /// All the diagonal steps, then all the straight steps (see [Path::from_vector]).
+    #[default]
+    DiagonalsFirst,
+    /// All the straight steps, then all the diagonal steps.
+    StraightsFirst,
+    /// The diagonal steps spread evenly among the straight ones (see [Path::line]).
+    Interleaved,
+    /// Only straight steps, spread as evenly as possible (see [Path::supercover_line]).
+    Orthogonal,
+    /// As many steps as possible in the heading of the first (non-center) step of the
+    /// original path, then the diagonal steps and the straight steps. Behaves as
+    /// [Normalization::DiagonalsFirst] when that heading leads away from the target.
+    PreserveFirstHeading,
+}
+
+impl Path {
+    /// Returns a normalized `Path`, shaped according to the given `Normalization`.
+    ///
+    /// As with [Path::normalized], the resulting `Path` has the same displacement as the
+    /// current one and a minimal length (see [Normalization] for the metrics).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Normalization, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::Right, Balance::Right, Balance::Top]);
+    /// let normalized = path.normalized_with(Normalization::PreserveFirstHeading);
+    /// assert_eq!(normalized, Path::new(vec![Balance::TopRight, Balance::TopRight]));
+    ///
+    /// let path = Path::new(vec![Balance::Right, Balance::Top, Balance::Right, Balance::Right]);
+    /// let normalized = path.normalized_with(Normalization::PreserveFirstHeading);
+    /// assert_eq!(normalized, Path::new(vec![Balance::Right, Balance::Right, Balance::TopRight]));
+    /// assert_eq!(normalized.to_vector(), path.to_vector());
+    /// ```
+    pub fn normalized_with(&self, normalization: Normalization) -> Self {
+        let offset = self.displacement::<i64>();
+        match normalization {
+            Normalization::DiagonalsFirst => Self::from_offset(offset),
+            Normalization::StraightsFirst => {
+                let diagonal = offset.direction();
+                let straights = Self::from_offset(offset - Self::diagonal_part(offset));
+                let mut movements = straights.raw;
+                let count = offset.x.abs().min(offset.y.abs());
+                movements.extend((0..count).map(|_| diagonal));
+                Self::new(movements)
+            }
+            Normalization::Interleaved => Self::line(Position::ORIGIN, Position::ORIGIN + offset),
+            Normalization::Orthogonal => {
+                Self::supercover_line(Position::ORIGIN, Position::ORIGIN + offset)
+            }
+            Normalization::PreserveFirstHeading => {
+                let heading = self.raw.iter().find(|&&b| b != Balance::Center);
+                let mut movements = Vec::new();
+                let mut rest = offset;
+                if let Some(&heading) = heading {
+                    let step = Offset::from(heading);
+                    while rest != Offset::ZERO
+                        && Self::chebyshev(rest - step) < Self::chebyshev(rest)
+                    {
+                        rest = rest - step;
+                        movements.push(heading);
+                    }
+                }
+                movements.extend(Self::from_offset(rest).raw);
+                Self::new(movements)
+            }
+        }
+    }
+
+    /// Returns the offset covered by the diagonal steps of a minimal path.
+    fn diagonal_part(offset: Offset<i64>) -> Offset<i64> {
+        let count = offset.x.abs().min(offset.y.abs());
+        Offset::new(offset.x.signum() * count, offset.y.signum() * count)
+    }
+
+    /// Returns the Chebyshev length of an offset: the number of steps of a minimal path.
+    fn chebyshev(offset: Offset<i64>) -> i64 {
+        offset.x.abs().max(offset.y.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Normalization, Path};
+
+    const STRATEGIES: [Normalization; 5] = [
+        Normalization::DiagonalsFirst,
+        Normalization::StraightsFirst,
+        Normalization::Interleaved,
+        Normalization::Orthogonal,
+        Normalization::PreserveFirstHeading,
+    ];
+
+    #[test]
+    fn test_displacement_and_length() {
+        for first in Balance::ALL {
+            for x in -4..=4 {
+                for y in -4..=4 {
+                    let mut path = Path::new(vec![first]);
+                    path.push(-first);
+                    let rest = Path::from_vector(x, y);
+                    for &movement in rest.iter() {
+                        path.push(movement);
+                    }
+                    for strategy in STRATEGIES {
+                        let normalized = path.normalized_with(strategy);
+                        assert_eq!(normalized.to_vector(), (x, y), "{:?}", strategy);
+                        let expected = match strategy {
+                            Normalization::Orthogonal => x.abs() + y.abs(),
+                            _ => x.abs().max(y.abs()),
+                        };
+                        assert_eq!(normalized.len(), expected as usize, "{:?}", strategy);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Balance, BalanceError};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Selects a textual representation of a `Balance`.
+///
+/// Every notation maps each of the nine `Balance` variants to a distinct string,
+/// so that [Balance::to_notation] and [Balance::from_notation] round-trip.
+///
+/// | `Balance`     | `Emoji` | `EmojiText` | `Arrow` | `Ascii` | `Compass` | `Name`        | `Value` |
+/// |---------------|---------|-------------|---------|---------|-----------|---------------|---------|
+/// | `TopLeft`     | ↖️      | ↖           | ↖       | `<^`    | `NW`      | `TopLeft`     | `-4`    |
+/// | `Top`         | ⬆️      | ⬆           | ↑       | `^`     | `N`       | `Top`         | `-3`    |
+/// | `TopRight`    | ↗️      | ↗           | ↗       | `^>`    | `NE`      | `TopRight`    | `-2`    |
+/// | `Left`        | ⬅️      | ⬅           | ←       | `<`     | `W`       | `Left`        | `-1`    |
+/// | `Center`      | ⏺️      | ⏺           | ·       | `.`     | `C`       | `Center`      | `0`     |
+/// | `Right`       | ➡️      | ➡           | →       | `>`     | `E`       | `Right`       | `1`     |
+/// | `BottomLeft`  | ↙️      | ↙           | ↙       | `<v`    | `SW`      | `BottomLeft`  | `2`     |
+/// | `Bottom`      | ⬇️      | ⬇           | ↓       | `v`     | `S`       | `Bottom`      | `3`     |
+/// | `BottomRight` | ↘️      | ↘           | ↘       | `v>`    | `SE`      | `BottomRight` | `4`     |
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Notation};
+///
+/// assert_eq!(Balance::TopRight.to_notation(Notation::Compass), "NE");
+/// assert_eq!(Balance::from_notation("NE", Notation::Compass), Ok(Balance::TopRight));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Notation {
+    /// Emoji with a variation selector (`U+FE0F`), as returned by [Balance::to_symbol].
+    Emoji,
+    /// Emoji without variation selector.
+    EmojiText,
+    /// Plain Unicode arrows.
+    Arrow,
+    /// ASCII characters only.
+    Ascii,
+    /// 8-point compass names, plus `C` for `Balance::Center`.
+    Compass,
+    /// The names of the `Balance` variants.
+    Name,
+    /// The digits of [Balance::to_value].
+    Value,
+}
+
+impl Notation {
+    /// All the notations, in the order tried by [Balance::from_str](core::str::FromStr::from_str).
+    pub const ALL: [Notation; 7] = [
+        Notation::Emoji,
+        Notation::EmojiText,
+        Notation::Arrow,
+        Notation::Ascii,
+        Notation::Compass,
+        Notation::Name,
+        Notation::Value,
+    ];
+}
+
+impl Balance {
+    /// Converts the current `Balance` position into its representation in the given `Notation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Notation};
+    ///
+    /// let balance = Balance::BottomLeft;
+    /// assert_eq!(balance.to_notation(Notation::Emoji), "↙️");
+    /// assert_eq!(balance.to_notation(Notation::Ascii), "<v");
+    /// assert_eq!(balance.to_notation(Notation::Compass), "SW");
+    /// assert_eq!(balance.to_notation(Notation::Name), "BottomLeft");
+    /// assert_eq!(balance.to_notation(Notation::Value), "2");
+    /// ```
+    pub const fn to_notation(self, notation: Notation) -> &'static str {
+        match notation {
+            Notation::Emoji => self.to_symbol(),
+            Notation::EmojiText => match self {
+                Balance::TopLeft => "↖",
+                Balance::Top => "⬆",
+                Balance::TopRight => "↗",
+                Balance::Left => "⬅",
+                Balance::Center => "⏺",
+                Balance::Right => "➡",
+                Balance::BottomLeft => "↙",
+                Balance::Bottom => "⬇",
+                Balance::BottomRight => "↘",
+            },
+            Notation::Arrow => match self {
+                Balance::TopLeft => "↖",
+                Balance::Top => "↑",
+                Balance::TopRight => "↗",
+                Balance::Left => "←",
+                Balance::Center => "·",
+                Balance::Right => "→",
+                Balance::BottomLeft => "↙",
+                Balance::Bottom => "↓",
+                Balance::BottomRight => "↘",
+            },
+            Notation::Ascii => match self {
+                Balance::TopLeft => "<^",
+                Balance::Top => "^",
+                Balance::TopRight => "^>",
+                Balance::Left => "<",
+                Balance::Center => ".",
+                Balance::Right => ">",
+                Balance::BottomLeft => "<v",
+                Balance::Bottom => "v",
+                Balance::BottomRight => "v>",
+            },
+            Notation::Compass => match self {
+                Balance::TopLeft => "NW",
+                Balance::Top => "N",
+                Balance::TopRight => "NE",
+                Balance::Left => "W",
+                Balance::Center => "C",
+                Balance::Right => "E",
+                Balance::BottomLeft => "SW",
+                Balance::Bottom => "S",
+                Balance::BottomRight => "SE",
+            },
+            Notation::Name => match self {
+                Balance::TopLeft => "TopLeft",
+                Balance::Top => "Top",
+                Balance::TopRight => "TopRight",
+                Balance::Left => "Left",
+                Balance::Center => "Center",
+                Balance::Right => "Right",
+                Balance::BottomLeft => "BottomLeft",
+                Balance::Bottom => "Bottom",
+                Balance::BottomRight => "BottomRight",
+            },
+            Notation::Value => match self {
+                Balance::TopLeft => "-4",
+                Balance::Top => "-3",
+                Balance::TopRight => "-2",
+                Balance::Left => "-1",
+                Balance::Center => "0",
+                Balance::Right => "1",
+                Balance::BottomLeft => "2",
+                Balance::Bottom => "3",
+                Balance::BottomRight => "4",
+            },
+        }
+    }
+
+    /// Parses a `Balance` from its representation in the given `Notation`.
+    ///
+    /// Leading and trailing whitespaces are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::InvalidSymbol] if the string is not a representation
+    /// of a `Balance` in the given `Notation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError, Notation};
+    ///
+    /// assert_eq!(Balance::from_notation("↑", Notation::Arrow), Ok(Balance::Top));
+    /// assert_eq!(Balance::from_notation(" v> ", Notation::Ascii), Ok(Balance::BottomRight));
+    /// assert_eq!(Balance::from_notation("↑", Notation::Ascii), Err(BalanceError::InvalidSymbol));
+    /// ```
+    pub fn from_notation(s: &str, notation: Notation) -> Result<Self, BalanceError> {
+        let s = s.trim();
+        Balance::ALL
+            .into_iter()
+            .find(|balance| balance.to_notation(notation) == s)
+            .ok_or(BalanceError::InvalidSymbol)
+    }
+}
+
+/// Formats a `Balance` with [Notation::Emoji] (see [Balance::to_symbol]),
+/// or with [Notation::Name] when using the alternate flag (`{:#}`).
+/// The width, fill and alignment of the format are honoured.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::Balance;
+///
+/// assert_eq!(format!("{}", Balance::Top), "⬆️");
+/// assert_eq!(format!("{:#}", Balance::Top), "Top");
+/// assert_eq!(format!("{:>#5}|{:<#5}", Balance::Top, Balance::Left), "  Top|Left ");
+/// ```
+impl Display for Balance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.pad(self.to_notation(Notation::Name))
+        } else {
+            f.pad(self.to_symbol())
+        }
+    }
+}
+
+/// Parses a `Balance` written in any [Notation].
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::Balance;
+///
+/// assert_eq!("⬆️".parse(), Ok(Balance::Top));
+/// assert_eq!("SE".parse(), Ok(Balance::BottomRight));
+/// assert_eq!("Center".parse(), Ok(Balance::Center));
+/// assert_eq!("-1".parse(), Ok(Balance::Left));
+/// assert!("up".parse::<Balance>().is_err());
+/// ```
+impl FromStr for Balance {
+    type Err = BalanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Notation::ALL
+            .into_iter()
+            .find_map(|notation| Self::from_notation(s, notation).ok())
+            .ok_or(BalanceError::InvalidSymbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_round_trips() {
+        for notation in Notation::ALL {
+            for balance in Balance::ALL {
+                let text = balance.to_notation(notation);
+                assert_eq!(Balance::from_notation(text, notation), Ok(balance));
+                assert_eq!(text.parse(), Ok(balance));
+            }
+        }
+        for balance in Balance::ALL {
+            assert_eq!(format!("{}", balance).parse(), Ok(balance));
+            assert_eq!(format!("{:#}", balance).parse(), Ok(balance));
+        }
+    }
+}
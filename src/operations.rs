@@ -224,6 +224,52 @@ impl Balance {
         Self::from_vector(-y, x)
     }
 
+    /// Rotates the current position 45 degrees counterclockwise around the center of the 3x3 grid.
+    ///
+    /// # Returns
+    ///
+    /// The next `Balance` variant on the ring of the eight directions, counterclockwise.
+    /// For example, `Balance::Right` becomes `Balance::TopRight`, which becomes `Balance::Top`.
+    /// The center position (`Balance::Center`) remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::Right.rotate_ccw_45(), Balance::TopRight);
+    /// assert_eq!(Balance::TopRight.rotate_ccw_45(), Balance::Top);
+    /// assert_eq!(Balance::Center.rotate_ccw_45(), Balance::Center);
+    /// assert_eq!(Balance::Top.rotate_ccw_45().rotate_ccw_45(), Balance::Top.rotate_left());
+    /// ```
+    pub const fn rotate_ccw_45(self) -> Self {
+        let (x, y) = self.to_vector();
+        Self::from_vector((x + y).signum(), (y - x).signum())
+    }
+
+    /// Rotates the current position 45 degrees clockwise around the center of the 3x3 grid.
+    ///
+    /// # Returns
+    ///
+    /// The next `Balance` variant on the ring of the eight directions, clockwise.
+    /// For example, `Balance::Top` becomes `Balance::TopRight`, which becomes `Balance::Right`.
+    /// The center position (`Balance::Center`) remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::Top.rotate_cw_45(), Balance::TopRight);
+    /// assert_eq!(Balance::TopRight.rotate_cw_45(), Balance::Right);
+    /// assert_eq!(Balance::Center.rotate_cw_45(), Balance::Center);
+    /// assert_eq!(Balance::Top.rotate_cw_45().rotate_cw_45(), Balance::Top.rotate_right());
+    /// ```
+    pub const fn rotate_cw_45(self) -> Self {
+        let (x, y) = self.to_vector();
+        Self::from_vector((x - y).signum(), (x + y).signum())
+    }
+
     /// Centers the current position horizontally in the 3x3 grid by setting the x-coordinate to 0.
     ///
     /// # Returns
@@ -1,5 +1,8 @@
-use crate::Balance;
+use crate::{Balance, Coordinate, MinimalSteps, Offset, PathSlice, Position, Steps};
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Deref, DerefMut, Index, IndexMut, Mul, RangeBounds};
+use core::slice::SliceIndex;
 
 /// Represents a sequence of movements in a grid, where each movement
 /// is represented by a `Balance` value indicating the direction of one step.
@@ -35,9 +38,24 @@ use alloc::vec::Vec;
 /// let path = Path::new(movements).reversed();
 /// assert_eq!(path.to_vector(), (1, -1));
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Using a `Path` as a sequence:
+/// ```
+/// use balanced_direction::{Balance, Path};
+///
+/// let mut path: Path = [Balance::Top, Balance::Right].into_iter().collect();
+/// path.extend([Balance::Bottom]);
+/// assert_eq!(path[1..], [Balance::Right, Balance::Bottom]);
+/// assert!(path.contains(&Balance::Bottom));
+///
+/// let square = Path::new(vec![Balance::Right, Balance::Bottom, Balance::Left, Balance::Top]);
+/// let twice = square.clone() * 2;
+/// assert_eq!(twice.len(), 8);
+/// assert!((path + Path::new(vec![Balance::Left])).is_closed());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub struct Path {
-    raw: Vec<Balance>,
+    pub(crate) raw: Vec<Balance>,
 }
 
 impl Path {
@@ -112,6 +130,11 @@ impl Path {
         self.raw.iter_mut()
     }
 
+    /// Returns a [PathSlice] view of the movements of the `Path`.
+    pub fn as_path_slice(&self) -> PathSlice<'_> {
+        PathSlice::new(&self.raw)
+    }
+
     /// Appends a new movement to the end of the `Path`.
     ///
     /// # Arguments
@@ -135,6 +158,93 @@ impl Path {
         self.raw.clear();
     }
 
+    /// Inserts a movement at position `index`, shifting the following movements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len()`.
+    pub fn insert(&mut self, index: usize, movement: Balance) {
+        self.raw.insert(index, movement);
+    }
+
+    /// Removes and returns the movement at position `index`, shifting the following movements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len()`.
+    pub fn remove(&mut self, index: usize) -> Balance {
+        self.raw.remove(index)
+    }
+
+    /// Replaces the movements in `range` with `replace_with`, and returns the removed movements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let mut path = Path::new(vec![Balance::Top, Balance::TopRight, Balance::Right]);
+    /// let removed = path.splice(1..2, [Balance::Top, Balance::Right]);
+    /// assert_eq!(removed, Path::new(vec![Balance::TopRight]));
+    /// assert_eq!(path.len(), 4);
+    /// assert_eq!(path.to_vector(), (2, -2));
+    /// ```
+    pub fn splice(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replace_with: impl IntoIterator<Item = Balance>,
+    ) -> Self {
+        self.raw.splice(range, replace_with).collect()
+    }
+
+    /// Splits the `Path` in two at `mid`: the movements before `mid`, and the others.
+    ///
+    /// Use the slice method (`path[..].split_at(mid)`) to borrow the halves instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::Left, Balance::Bottom]);
+    /// let (head, tail) = path.split_at(1);
+    /// assert_eq!(head, Path::new(vec![Balance::Top]));
+    /// assert_eq!(head + tail, path);
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        let (head, tail) = self.raw.split_at(mid);
+        (Self::new(head.to_vec()), Self::new(tail.to_vec()))
+    }
+
+    /// Returns the `Path` made of the movements of this `Path` followed by those of `other`.
+    ///
+    /// This is the `Path` of `self + other`, without consuming the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let there = Path::new(vec![Balance::Right, Balance::Right]);
+    /// let round_trip = there.concat(&there.inverse());
+    /// assert_eq!(round_trip.len(), 4);
+    /// assert!(round_trip.is_closed());
+    /// ```
+    pub fn concat(&self, other: &Self) -> Self {
+        let mut movements = Vec::with_capacity(self.raw.len() + other.raw.len());
+        movements.extend_from_slice(&self.raw);
+        movements.extend_from_slice(&other.raw);
+        Self { raw: movements }
+    }
+
     /// Converts the sequence of movements in the `Path` to a vector representation.
     ///
     /// Each `Balance` value in the `Path` contributes a two-dimensional `(i8, i8)` vector,
@@ -147,6 +257,9 @@ impl Path {
     /// - The first element is the cumulative movement along the x-axis.
     /// - The second element is the cumulative movement along the y-axis.
     ///
+    /// The sum overflows after 127 steps in the same direction: use [Path::displacement]
+    /// with a wider coordinate type for long paths.
+    ///
     /// # Examples
     ///
     /// ```
@@ -158,14 +271,62 @@ impl Path {
     /// assert_eq!(vector, (1, -2)); // 1 step right, 2 steps up
     /// ```
     pub fn to_vector(&self) -> (i8, i8) {
-        let mut x = 0;
-        let mut y = 0;
-        for movement in self.raw.iter() {
-            let (a, b) = movement.to_vector();
-            x += a;
-            y += b;
-        }
-        (x, y)
+        self.as_path_slice().to_vector()
+    }
+
+    /// Returns the cumulative movement of the `Path` as an [Offset] with coordinates of type `T`.
+    ///
+    /// This is the wide counterpart of [Path::to_vector].
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a component of the sum overflows `T`.
+    /// Use [Path::checked_displacement] for a non-panicking sum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Offset, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Right; 300]);
+    /// assert_eq!(path.displacement::<i32>(), Offset::new(300, 0));
+    /// ```
+    pub fn displacement<T: Coordinate>(&self) -> Offset<T> {
+        self.as_path_slice().displacement()
+    }
+
+    /// Returns the cumulative movement of the `Path`, or `None` if a component overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Offset, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Bottom; 200]);
+    /// assert_eq!(path.checked_displacement::<i8>(), None);
+    /// assert_eq!(path.checked_displacement::<i16>(), Some(Offset::new(0, 200)));
+    /// ```
+    pub fn checked_displacement<T: Coordinate>(&self) -> Option<Offset<T>> {
+        self.raw
+            .iter()
+            .try_fold(Offset::ZERO, |offset, movement| {
+                offset.checked_add(Offset::from(*movement))
+            })
+    }
+
+    /// Converts an [Offset] into a `Path`, as [Path::from_vector] does for `(i8, i8)` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Offset, Path};
+    ///
+    /// let path = Path::from_offset(Offset::new(-150i32, 20));
+    /// assert_eq!(path.len(), 150);
+    /// assert_eq!(path.displacement(), Offset::new(-150, 20));
+    /// ```
+    pub fn from_offset<T: Coordinate>(offset: Offset<T>) -> Self {
+        MinimalSteps::from_offset(offset).collect()
     }
 
     /// Converts a vector representation `(x, y)` into a `Path`.
@@ -196,16 +357,7 @@ impl Path {
     /// assert_eq!(path.to_vector(), (2, -1));
     /// ```
     pub fn from_vector(x: i8, y: i8) -> Self {
-        let mut movements = Vec::new();
-        let mut x = x;
-        let mut y = y;
-        while x != 0 || y != 0 {
-            let (a, b) = (x.signum(), y.signum());
-            x -= a;
-            y -= b;
-            movements.push(Balance::from_vector(a, b));
-        }
-        Self { raw: movements }
+        Self::from_offset(Offset::new(x, y))
     }
 
     /// Returns a normalized `Path`.
@@ -215,7 +367,8 @@ impl Path {
     /// and then converting this vector back into a `Path` using `from_vector`.
     ///
     /// This effectively removes redundant steps in the `Path` that cancel each other out,
-    /// resulting in a minimal representation of the net movement.
+    /// resulting in a minimal representation of the net movement. The diagonal steps come
+    /// first: see [Path::normalized_with] for the other shapes.
     ///
     /// # Examples
     ///
@@ -228,8 +381,7 @@ impl Path {
     /// assert_eq!(normalized_path.to_vector(), (2, 0)); // Two steps right
     /// ```
     pub fn normalized(&self) -> Self {
-        let (x, y) = self.to_vector();
-        Self::from_vector(x, y)
+        self.as_path_slice().normalized().collect_path()
     }
 
     /// Reverses the sequence of movements in the `Path`.
@@ -254,9 +406,137 @@ impl Path {
     /// assert_eq!(reversed_path.to_vector(), (0, -1));
     /// ```
     pub fn reversed(&self) -> Self {
-        let mut movements = Vec::new();
+        self.as_path_slice().reversed().collect_path()
+    }
+
+    /// Returns the `Path` going back along the current one.
+    ///
+    /// The movements are taken in reverse order and each one is negated (see
+    /// [Balance::neg](core::ops::Neg::neg)), so that following a `Path` then its inverse
+    /// leads back to the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Top, Balance::TopRight]);
+    /// assert_eq!(path.inverse(), Path::new(vec![Balance::BottomLeft, Balance::Bottom]));
+    /// assert_eq!(path.inverse().to_vector(), (-1, 2));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut movements = Vec::with_capacity(self.raw.len());
         for movement in self.raw.iter().rev() {
-            movements.push(*movement);
+            movements.push(-*movement);
+        }
+        Self { raw: movements }
+    }
+
+    /// Returns the `Path` without its immediate back-tracking.
+    ///
+    /// Each pair of adjacent opposite movements (`Top` then `Bottom`, `TopLeft` then
+    /// `BottomRight`, ...) is removed, repeatedly, until no such pair remains. Unlike
+    /// [Path::normalized], the order of the remaining movements is preserved.
+    ///
+    /// `Balance::Center` movements are kept, and thus separate the movements around them:
+    /// use [Path::remove_center_steps] first to ignore them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![
+    ///     Balance::Top,
+    ///     Balance::Right,
+    ///     Balance::TopLeft,
+    ///     Balance::BottomRight,
+    ///     Balance::Left,
+    ///     Balance::Right,
+    /// ]);
+    /// assert_eq!(path.reduced(), Path::new(vec![Balance::Top, Balance::Right]));
+    /// ```
+    pub fn reduced(&self) -> Self {
+        let mut movements: Vec<Balance> = Vec::with_capacity(self.raw.len());
+        for &movement in self.raw.iter() {
+            match movements.last() {
+                Some(&last) if last != Balance::Center && last == -movement => {
+                    movements.pop();
+                }
+                _ => movements.push(movement),
+            }
+        }
+        Self { raw: movements }
+    }
+
+    /// Returns the `Path` without its `Balance::Center` movements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![Balance::Center, Balance::Left, Balance::Center]);
+    /// assert_eq!(path.remove_center_steps(), Path::new(vec![Balance::Left]));
+    /// ```
+    pub fn remove_center_steps(&self) -> Self {
+        let mut movements = Vec::new();
+        for &movement in self.raw.iter() {
+            if movement != Balance::Center {
+                movements.push(movement);
+            }
+        }
+        Self { raw: movements }
+    }
+
+    /// Returns the loop-erased `Path`: the cycles of the route are removed in the order
+    /// they are closed.
+    ///
+    /// Following the movements, each time a position visited earlier is reached again, the
+    /// movements made since the first visit are erased. The resulting `Path` goes from the
+    /// same start to the same end, and never visits a position twice.
+    /// `Balance::Center` movements, which stay on the same position, are always erased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path};
+    ///
+    /// let path = Path::new(vec![
+    ///     Balance::Right,
+    ///     Balance::Top,
+    ///     Balance::Left,
+    ///     Balance::Bottom,
+    ///     Balance::Bottom,
+    ///     Balance::Center,
+    ///     Balance::Right,
+    /// ]);
+    /// assert_eq!(path.loop_erased(), Path::new(vec![Balance::Bottom, Balance::Right]));
+    /// ```
+    pub fn loop_erased(&self) -> Self {
+        let mut visited = BTreeMap::new();
+        let mut position = Position::<i64>::ORIGIN;
+        let mut movements = Vec::new();
+        visited.insert(position, 0);
+        for &movement in self.raw.iter() {
+            position += movement;
+            match visited.get(&position) {
+                Some(&index) => {
+                    // Forget the positions visited since the first visit.
+                    let mut erased = position;
+                    for &erased_movement in movements[index..].iter() {
+                        erased += erased_movement;
+                        if erased != position {
+                            visited.remove(&erased);
+                        }
+                    }
+                    movements.truncate(index);
+                }
+                None => {
+                    movements.push(movement);
+                    visited.insert(position, movements.len());
+                }
+            }
         }
         Self { raw: movements }
     }
@@ -269,7 +549,7 @@ impl Path {
     /// # Arguments
     ///
     /// * `f` - A function or closure of type `Fn(Balance) -> Balance` that takes a `Balance` as input
-    ///         and returns a transformed `Balance`.
+    ///   and returns a transformed `Balance`.
     ///
     /// # Returns
     ///
@@ -290,11 +570,7 @@ impl Path {
     /// );
     /// ```
     pub fn each(&self, f: impl Fn(Balance) -> Balance) -> Self {
-        let mut movements = Vec::with_capacity(self.raw.len());
-        for movement in self.raw.iter() {
-            movements.push(f(*movement));
-        }
-        Self { raw: movements }
+        self.as_path_slice().each(f).collect_path()
     }
 
     /// Applies a function `f`, which takes two arguments of type `Balance`,
@@ -308,7 +584,7 @@ impl Path {
     /// # Arguments
     ///
     /// * `f` - A function or closure of type `Fn(Balance, Balance) -> Balance` that
-    ///         takes two `Balance` arguments and returns a transformed `Balance`.
+    ///   takes two `Balance` arguments and returns a transformed `Balance`.
     /// * `other` - A `Balance` value that is passed as the second argument to the function `f`.
     ///
     /// # Returns
@@ -328,11 +604,7 @@ impl Path {
     /// assert_eq!(modified_path.to_vector(), (0, -1));
     /// ```
     pub fn each_with(&self, f: impl Fn(Balance, Balance) -> Balance, other: Balance) -> Self {
-        let mut movements = Vec::with_capacity(self.raw.len());
-        for movement in self.raw.iter() {
-            movements.push(f(*movement, other));
-        }
-        Self { raw: movements }
+        self.as_path_slice().each_with(f, other).collect_path()
     }
 
     /// Applies a function `f` to corresponding pairs of `Balance` values from the current `Path`
@@ -344,19 +616,18 @@ impl Path {
     /// # Arguments
     ///
     /// * `f` - A function or closure of type `Fn(Balance, Balance) -> Balance` that takes two
-    ///         `Balance` arguments (one from each `Path`) and returns a transformed `Balance`.
+    ///   `Balance` arguments (one from each `Path`) and returns a transformed `Balance`.
     /// * `other` - A reference to another `Path` whose `Balance` values will be paired with those of
-    ///             the current `Path`.
+    ///   the current `Path`.
     ///
     /// # Returns
     ///
     /// A new `Path` where each `Balance` is the result of applying `f` to corresponding pairs of
     /// `Balance` values from the current `Path` and the `other` `Path`.
     ///
-    /// # Panics
-    ///
-    /// Panics if the lengths of the two `Path`s are not equal, as the method expects both `Path`s
-    /// to contain the same number of movements.
+    /// The resulting `Path` stops at the end of the shorter one
+    /// ([ZipPolicy::Truncate](crate::ZipPolicy::Truncate)). Use [Path::try_each_zip] to reject
+    /// paths of different lengths, or [Path::each_zip_with_policy] to extend the shorter one.
     ///
     /// # Examples
     ///
@@ -371,10 +642,202 @@ impl Path {
     /// assert_eq!(result.to_vector(), (0, 0));
     /// ```
     pub fn each_zip(&self, f: impl Fn(Balance, Balance) -> Balance, other: &Self) -> Self {
-        let mut movements = Vec::with_capacity(self.raw.len());
-        for (a, b) in self.raw.iter().zip(other.raw.iter()) {
-            movements.push(f(*a, *b));
+        self.as_path_slice()
+            .each_zip(f, other.as_path_slice())
+            .collect_path()
+    }
+}
+
+impl From<Vec<Balance>> for Path {
+    fn from(movements: Vec<Balance>) -> Self {
+        Self::new(movements)
+    }
+}
+
+impl From<Path> for Vec<Balance> {
+    fn from(path: Path) -> Self {
+        path.raw
+    }
+}
+
+impl FromIterator<Balance> for Path {
+    fn from_iter<T: IntoIterator<Item = Balance>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Balance> for Path {
+    fn extend<T: IntoIterator<Item = Balance>>(&mut self, iter: T) {
+        self.raw.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a Balance> for Path {
+    fn extend<T: IntoIterator<Item = &'a Balance>>(&mut self, iter: T) {
+        self.raw.extend(iter);
+    }
+}
+
+impl IntoIterator for Path {
+    type Item = Balance;
+    type IntoIter = alloc::vec::IntoIter<Balance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Path {
+    type Item = &'a Balance;
+    type IntoIter = core::slice::Iter<'a, Balance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Path {
+    type Item = &'a mut Balance;
+    type IntoIter = core::slice::IterMut<'a, Balance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.iter_mut()
+    }
+}
+
+impl Deref for Path {
+    type Target = [Balance];
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl DerefMut for Path {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.raw
+    }
+}
+
+impl<'a> From<&'a Path> for PathSlice<'a> {
+    fn from(path: &'a Path) -> Self {
+        path.as_path_slice()
+    }
+}
+
+impl AsRef<[Balance]> for Path {
+    fn as_ref(&self) -> &[Balance] {
+        &self.raw
+    }
+}
+
+impl<I: SliceIndex<[Balance]>> Index<I> for Path {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.raw[index]
+    }
+}
+
+impl<I: SliceIndex<[Balance]>> IndexMut<I> for Path {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.raw[index]
+    }
+}
+
+impl Add for Path {
+    type Output = Self;
+
+    /// Concatenates two paths (see [Path::concat]).
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.raw.extend(rhs.raw);
+        self
+    }
+}
+
+impl AddAssign for Path {
+    fn add_assign(&mut self, rhs: Self) {
+        self.raw.extend(rhs.raw);
+    }
+}
+
+impl Mul<usize> for Path {
+    type Output = Self;
+
+    /// Repeats the movements of the path `rhs` times.
+    fn mul(self, rhs: usize) -> Self::Output {
+        Self::new(self.raw.repeat(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Path, Position};
+
+    /// A deterministic wandering path, with back-tracking and cycles.
+    fn wandering_path() -> Path {
+        let mut movements = Vec::new();
+        let mut seed = 7u32;
+        for _ in 0..200 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            movements.push(Balance::ALL[(seed >> 16) as usize % 9]);
         }
-        Self { raw: movements }
+        Path::new(movements)
+    }
+
+    #[test]
+    fn test_reductions_keep_displacement() {
+        let path = wandering_path();
+        let reduced = path.remove_center_steps().reduced();
+        assert_eq!(reduced.displacement::<i32>(), path.displacement());
+        assert_eq!(reduced.reduced(), reduced);
+        for pair in reduced.raw.windows(2) {
+            assert_ne!(pair[0], -pair[1]);
+        }
+
+        let erased = path.loop_erased();
+        assert_eq!(erased.displacement::<i32>(), path.displacement());
+        let mut position = Position::<i32>::ORIGIN;
+        let mut visited = vec![position];
+        for &movement in erased.iter() {
+            position += movement;
+            assert!(!visited.contains(&position));
+            visited.push(position);
+        }
+
+        let mut round_trip = path.clone();
+        for &movement in path.inverse().iter() {
+            round_trip.push(movement);
+        }
+        assert!(round_trip.remove_center_steps().reduced().is_empty());
+    }
+
+    #[test]
+    fn test_sequence_operations() {
+        let path = wandering_path();
+        let (head, tail) = path.split_at(120);
+        assert_eq!(head.len(), 120);
+        assert_eq!(head.concat(&tail), path);
+        assert_eq!(head.clone() + tail.clone(), path);
+
+        let mut spliced = path.clone();
+        let removed = spliced.splice(10..20, []);
+        assert_eq!(removed[..], path[10..20]);
+        spliced.splice(10..10, removed);
+        assert_eq!(spliced, path);
+
+        let mut edited = path.clone();
+        let movement = edited.remove(5);
+        edited.insert(5, movement);
+        assert_eq!(edited, path);
+
+        let repeated = path.clone() * 3;
+        assert_eq!(repeated.len(), 3 * path.len());
+        let offset = path.displacement::<i32>();
+        assert_eq!(repeated.displacement(), offset + offset + offset);
+
+        let collected: Path = (&path).into_iter().map(|&b| -b).collect();
+        assert_eq!(collected.reversed(), path.inverse());
+        assert_eq!(Vec::from(path.clone()), path.to_vec());
     }
 }
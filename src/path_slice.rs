@@ -0,0 +1,223 @@
+use crate::{Balance, Coordinate, Offset, Steps, ZipWith};
+use core::iter::{repeat, Copied, FusedIterator, Map, Repeat, Rev};
+use core::ops::Deref;
+use core::slice::Iter;
+
+/// A borrowed sequence of movements: the allocation-free view of a path.
+///
+/// A `PathSlice` wraps a `&[Balance]` (the movements of a `Path`, of an [ArrayPath](crate::ArrayPath),
+/// or any slice) and provides the same analysis methods. As a view cannot hold new movements,
+/// the transformations ([PathSlice::normalized], [PathSlice::reversed], [PathSlice::each], ...)
+/// return iterators, which can be collected into a `Path` or an `ArrayPath`.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{ArrayPath, Balance, PathSlice};
+///
+/// let movements = [Balance::Top, Balance::Bottom, Balance::Right, Balance::TopRight];
+/// let path = PathSlice::new(&movements);
+/// assert_eq!(path.to_vector(), (2, -1));
+///
+/// let normalized: ArrayPath<4> = path.normalized().collect();
+/// assert_eq!(normalized.as_slice(), &[Balance::TopRight, Balance::Right]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub struct PathSlice<'a> {
+    raw: &'a [Balance],
+}
+
+impl<'a> PathSlice<'a> {
+    /// Creates a `PathSlice` over a slice of movements.
+    pub const fn new(movements: &'a [Balance]) -> Self {
+        Self { raw: movements }
+    }
+
+    /// Returns the movements of the `PathSlice`.
+    pub const fn as_slice(&self) -> &'a [Balance] {
+        self.raw
+    }
+
+    /// Returns the number of movements in the `PathSlice`.
+    pub const fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Checks whether the `PathSlice` is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    /// Retrieves the `Balance` at the specified index, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a Balance> {
+        self.raw.get(index)
+    }
+
+    /// Returns an iterator over references to the movements.
+    pub fn iter(&self) -> core::slice::Iter<'a, Balance> {
+        self.raw.iter()
+    }
+
+    /// Returns the cumulative movement of the `PathSlice` as an `(i8, i8)` vector.
+    ///
+    /// As for `Path::to_vector`, the sum overflows after 127 steps in the same direction:
+    /// use [PathSlice::displacement] for long paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, PathSlice};
+    ///
+    /// const PATROL: PathSlice = PathSlice::new(&[Balance::Top, Balance::Right, Balance::Top]);
+    /// const END: (i8, i8) = PATROL.to_vector();
+    /// assert_eq!(END, (1, -2));
+    /// ```
+    pub const fn to_vector(&self) -> (i8, i8) {
+        let mut x = 0;
+        let mut y = 0;
+        let mut i = 0;
+        while i < self.raw.len() {
+            let (a, b) = self.raw[i].to_vector();
+            x += a;
+            y += b;
+            i += 1;
+        }
+        (x, y)
+    }
+
+    /// Returns the cumulative movement of the `PathSlice` as an [Offset] with coordinates
+    /// of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a component of the sum overflows `T`.
+    pub fn displacement<T: Coordinate>(&self) -> Offset<T> {
+        self.steps().displacement()
+    }
+
+    /// Returns an iterator over the movements, by value, to chain the [Steps] adaptors.
+    pub fn steps(&self) -> Copied<Iter<'a, Balance>> {
+        self.raw.iter().copied()
+    }
+
+    /// Returns the movements of the minimal path with the same displacement, diagonal
+    /// steps first (see `Path::normalized`).
+    pub fn normalized(&self) -> MinimalSteps {
+        MinimalSteps::from_offset(self.displacement::<i64>())
+    }
+
+    /// Returns the movements in reverse order (see `Path::reversed`).
+    pub fn reversed(&self) -> Rev<Copied<Iter<'a, Balance>>> {
+        self.steps().rev()
+    }
+
+    /// Returns the results of `f` on each movement (see `Path::each`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, PathSlice};
+    ///
+    /// let movements = [Balance::Top, Balance::Left];
+    /// let mut rotated = PathSlice::new(&movements).each(Balance::rotate_left);
+    /// assert_eq!(rotated.next(), Some(Balance::Left));
+    /// assert_eq!(rotated.next(), Some(Balance::Bottom));
+    /// assert_eq!(rotated.next(), None);
+    /// ```
+    pub fn each<F: Fn(Balance) -> Balance>(&self, f: F) -> Map<Copied<Iter<'a, Balance>>, F> {
+        self.steps().map_balance(f)
+    }
+
+    /// Returns the results of `f` on each movement and `other` (see `Path::each_with`).
+    pub fn each_with<F: Fn(Balance, Balance) -> Balance>(
+        &self,
+        f: F,
+        other: Balance,
+    ) -> ZipWith<Copied<Iter<'a, Balance>>, Repeat<Balance>, F> {
+        self.steps().zip_with(f, repeat(other))
+    }
+
+    /// Returns the results of `f` on the pairs of movements of this `PathSlice` and `other`
+    /// (see `Path::each_zip`). The iteration stops at the end of the shorter one.
+    pub fn each_zip<'b, F: Fn(Balance, Balance) -> Balance>(
+        &self,
+        f: F,
+        other: PathSlice<'b>,
+    ) -> ZipWith<Copied<Iter<'a, Balance>>, Copied<Iter<'b, Balance>>, F> {
+        self.steps().zip_with(f, other.steps())
+    }
+}
+
+impl<'a> From<&'a [Balance]> for PathSlice<'a> {
+    fn from(movements: &'a [Balance]) -> Self {
+        Self::new(movements)
+    }
+}
+
+impl Deref for PathSlice<'_> {
+    type Target = [Balance];
+
+    fn deref(&self) -> &Self::Target {
+        self.raw
+    }
+}
+
+impl<'a> IntoIterator for PathSlice<'a> {
+    type Item = &'a Balance;
+    type IntoIter = core::slice::Iter<'a, Balance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.raw.iter()
+    }
+}
+
+/// An iterator over the movements of the minimal path with a given displacement, diagonal
+/// steps first.
+///
+/// Each step moves by the signs of the remaining displacement, so that the diagonal steps
+/// come first, then the straight ones. This `struct` is created by [PathSlice::normalized]
+/// or [MinimalSteps::from_offset].
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, MinimalSteps, Offset};
+///
+/// let steps: Vec<Balance> = MinimalSteps::from_offset(Offset::new(2, -1)).collect();
+/// assert_eq!(steps, [Balance::TopRight, Balance::Right]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinimalSteps {
+    rest: Offset<i64>,
+}
+
+impl MinimalSteps {
+    /// Creates the iterator over the movements of the minimal path covering `offset`.
+    pub fn from_offset<T: Coordinate>(offset: Offset<T>) -> Self {
+        Self {
+            rest: Offset::new(offset.x.to_i128() as i64, offset.y.to_i128() as i64),
+        }
+    }
+}
+
+impl Iterator for MinimalSteps {
+    type Item = Balance;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest == Offset::ZERO {
+            return None;
+        }
+        let step = self.rest.direction();
+        self.rest = self.rest - Offset::from(step);
+        Some(step)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.x.unsigned_abs().max(self.rest.y.unsigned_abs()) as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MinimalSteps {}
+
+impl FusedIterator for MinimalSteps {}
@@ -0,0 +1,433 @@
+use crate::Balance;
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A signed integer type usable as a coordinate of a [Position] or an [Offset].
+///
+/// This trait is implemented for `i8`, `i16`, `i32`, `i64` and `isize`.
+pub trait Coordinate:
+    Copy + Ord + Debug + Hash + Default + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self>
+{
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// Converts a `Balance` coordinate (in the range `-1..=1`) to this type.
+    fn from_i8(value: i8) -> Self;
+
+    /// Converts the value to an `i128`, wide enough for the differences of any two values.
+    fn to_i128(self) -> i128;
+
+    /// Adds `rhs`, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts `rhs`, returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Adds `rhs`, clamping the result to the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtracts `rhs`, clamping the result to the bounds of the type.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Returns `-1`, `0` or `1` according to the sign of the value.
+    fn sign(self) -> i8 {
+        if self > Self::ZERO {
+            1
+        } else if self < Self::ZERO {
+            -1
+        } else {
+            0
+        }
+    }
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+
+                fn from_i8(value: i8) -> Self {
+                    value as $t
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize);
+
+/// A cell of an unbounded grid, with coordinates of type `T` (`i32` by default).
+///
+/// Like the vectors of `Balance`, the x-axis points right and the y-axis points down.
+/// Moving a `Position` by a `Balance` (`position + balance`) steps into the neighbouring
+/// cell, and the difference of two positions is an [Offset].
+///
+/// Positions are ordered by column, then by row, so that they can be used as keys of
+/// ordered collections.
+///
+/// The operators panic on overflow in debug builds, as the integer operators do; the
+/// `checked_*` and `saturating_*` methods handle the bounds of `T` explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Offset, Position};
+///
+/// let start: Position = Position::new(2, 5);
+/// let end = start + Balance::TopRight + Balance::Top;
+/// assert_eq!(end, Position::new(3, 3));
+/// assert_eq!(end - start, Offset::new(1, -2));
+/// assert_eq!(start.direction_to(end), Balance::TopRight);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Position<T = i32> {
+    /// The column of the cell, increasing to the right.
+    pub x: T,
+    /// The row of the cell, increasing downwards.
+    pub y: T,
+}
+
+/// The difference between two [Position]s, with coordinates of type `T` (`i32` by default).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Offset};
+///
+/// let offset: Offset<i64> = Offset::from(Balance::BottomLeft) + Balance::Left;
+/// assert_eq!(offset, Offset::new(-2, 1));
+/// assert_eq!(offset.direction(), Balance::BottomLeft);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub struct Offset<T = i32> {
+    /// The horizontal component, positive to the right.
+    pub x: T,
+    /// The vertical component, positive downwards.
+    pub y: T,
+}
+
+impl<T: Coordinate> Position<T> {
+    /// The position `(0, 0)`.
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    /// Creates a `Position` from its coordinates.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the `Balance` pointing from this position towards `other`.
+    ///
+    /// Each coordinate of the result is the sign of the corresponding coordinate of
+    /// `other - self`, so `Balance::Center` is returned when the positions are equal.
+    /// The difference itself is never computed, so this method cannot overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Position};
+    ///
+    /// let position = Position::new(0i16, 0);
+    /// assert_eq!(position.direction_to(Position::new(10, 0)), Balance::Right);
+    /// assert_eq!(position.direction_to(Position::new(-3, 7)), Balance::BottomLeft);
+    /// assert_eq!(position.direction_to(position), Balance::Center);
+    /// ```
+    pub fn direction_to(self, other: Self) -> Balance {
+        let sign = |a: T, b: T| b.cmp(&a) as i8;
+        Balance::from_vector(sign(self.x, other.x), sign(self.y, other.y))
+    }
+
+    /// Moves the position one step in the direction of `balance`, returning `None` if a
+    /// coordinate overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Position};
+    ///
+    /// let position = Position::new(i8::MAX, 0);
+    /// assert_eq!(position.checked_step(Balance::Right), None);
+    /// assert_eq!(position.checked_step(Balance::Left), Some(Position::new(126, 0)));
+    /// ```
+    pub fn checked_step(self, balance: Balance) -> Option<Self> {
+        self.checked_add(Offset::from(balance))
+    }
+
+    /// Moves the position one step in the direction of `balance`, clamping the coordinates
+    /// to the bounds of `T`.
+    pub fn saturating_step(self, balance: Balance) -> Self {
+        self.saturating_add(Offset::from(balance))
+    }
+
+    /// Moves the position by `offset`, returning `None` if a coordinate overflows.
+    pub fn checked_add(self, offset: Offset<T>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(offset.x)?,
+            self.y.checked_add(offset.y)?,
+        ))
+    }
+
+    /// Moves the position by `offset`, clamping the coordinates to the bounds of `T`.
+    pub fn saturating_add(self, offset: Offset<T>) -> Self {
+        Self::new(
+            self.x.saturating_add(offset.x),
+            self.y.saturating_add(offset.y),
+        )
+    }
+
+    /// Returns the offset from `other` to this position, or `None` if a component overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Offset, Position};
+    ///
+    /// let (a, b) = (Position::new(100i8, 0), Position::new(-100, 0));
+    /// assert_eq!(a.checked_sub(b), None);
+    /// assert_eq!(a.saturating_sub(b), Offset::new(127, 0));
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Offset<T>> {
+        Some(Offset::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    /// Returns the offset from `other` to this position, clamping the components to the
+    /// bounds of `T`.
+    pub fn saturating_sub(self, other: Self) -> Offset<T> {
+        Offset::new(
+            self.x.saturating_sub(other.x),
+            self.y.saturating_sub(other.y),
+        )
+    }
+}
+
+impl<T: Coordinate> Offset<T> {
+    /// The null offset `(0, 0)`.
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    /// Creates an `Offset` from its components.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the `Balance` whose coordinates are the signs of the components of the offset.
+    pub fn direction(self) -> Balance {
+        Balance::from_vector(self.x.sign(), self.y.sign())
+    }
+
+    /// Adds `other`, returning `None` if a component overflows.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// Adds `other`, clamping the components to the bounds of `T`.
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::new(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+        )
+    }
+}
+
+impl<T: Coordinate> From<Balance> for Offset<T> {
+    fn from(balance: Balance) -> Self {
+        let (x, y) = balance.to_vector();
+        Self::new(T::from_i8(x), T::from_i8(y))
+    }
+}
+
+impl<T> From<(T, T)> for Position<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Position<T>> for (T, T) {
+    fn from(position: Position<T>) -> Self {
+        (position.x, position.y)
+    }
+}
+
+impl<T> From<(T, T)> for Offset<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Offset<T>> for (T, T) {
+    fn from(offset: Offset<T>) -> Self {
+        (offset.x, offset.y)
+    }
+}
+
+impl<T: Coordinate> Add<Offset<T>> for Position<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Offset<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub<Offset<T>> for Position<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Offset<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Position<T> {
+    type Output = Offset<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Offset::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Add<Balance> for Position<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Balance) -> Self::Output {
+        self + Offset::from(rhs)
+    }
+}
+
+impl<T: Coordinate> Sub<Balance> for Position<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Balance) -> Self::Output {
+        self - Offset::from(rhs)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Balance> for Position<T> {
+    fn add_assign(&mut self, rhs: Balance) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign<Balance> for Position<T> {
+    fn sub_assign(&mut self, rhs: Balance) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> AddAssign<Offset<T>> for Position<T> {
+    fn add_assign(&mut self, rhs: Offset<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign<Offset<T>> for Position<T> {
+    fn sub_assign(&mut self, rhs: Offset<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coordinate> Add for Offset<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Offset<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Neg for Offset<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> Add<Balance> for Offset<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Balance) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Balance> for Offset<T> {
+    fn add_assign(&mut self, rhs: Balance) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> AddAssign for Offset<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Offset, Position};
+
+    #[test]
+    fn test_directions_match_balances() {
+        let origin = Position::<i64>::ORIGIN;
+        for balance in Balance::ALL {
+            let target = origin + balance;
+            assert_eq!(origin.direction_to(target), balance);
+            assert_eq!(target - origin, Offset::from(balance));
+            assert_eq!((target - origin).direction(), balance);
+            assert_eq!(target - balance, origin);
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        let corner = Position::new(i16::MIN, i16::MAX);
+        assert_eq!(corner.checked_step(Balance::BottomLeft), None);
+        assert_eq!(corner.saturating_step(Balance::BottomLeft), corner);
+        assert_eq!(
+            corner.checked_step(Balance::TopRight),
+            Some(Position::new(i16::MIN + 1, i16::MAX - 1))
+        );
+        let far = Position::new(i16::MAX, i16::MIN);
+        assert_eq!(corner.direction_to(far), Balance::TopRight);
+        assert_eq!(far.checked_sub(corner), None);
+        assert_eq!(far.saturating_sub(corner), Offset::new(i16::MAX, i16::MIN));
+    }
+}
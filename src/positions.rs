@@ -0,0 +1,217 @@
+use crate::{Balance, Coordinate, Offset, Path, Position, Positions, Steps};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::iter::Copied;
+use core::ops::Range;
+use core::slice::Iter;
+
+impl Path {
+    /// Returns an iterator over the positions visited by the `Path` from `start`.
+    ///
+    /// The iterator yields `start` first, then the position reached after each movement:
+    /// `len() + 1` positions in total.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, the iterator panics if a coordinate overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let path = Path::new(vec![Balance::Right, Balance::BottomRight]);
+    /// let positions: Vec<_> = path.positions(Position::new(10, 10)).collect();
+    /// assert_eq!(
+    ///     positions,
+    ///     [Position::new(10, 10), Position::new(11, 10), Position::new(12, 11)]
+    /// );
+    /// ```
+    pub fn positions<T: Coordinate>(
+        &self,
+        start: Position<T>,
+    ) -> Positions<Copied<Iter<'_, Balance>>, T> {
+        self.raw.iter().copied().positions(start)
+    }
+
+    /// Returns an [IndexedPath] answering position queries on this `Path` from `start`.
+    ///
+    /// The `IndexedPath` borrows the `Path` rather than copying it; use [IndexedPath::new]
+    /// to hand the `Path` over instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Position};
+    ///
+    /// let path = Path::new(vec![Balance::Right; 1000]);
+    /// let indexed = path.indexed(Position::new(0i64, 0));
+    /// assert_eq!(indexed.position_at(250), Some(Position::new(250, 0)));
+    /// ```
+    pub fn indexed<T: Coordinate>(&self, start: Position<T>) -> IndexedPath<'_, T> {
+        IndexedPath::from_cow(Cow::Borrowed(self), start)
+    }
+}
+
+/// A `Path` along with the positions it visits from a start, for fast position queries.
+///
+/// The `Path` is either owned ([IndexedPath::new]) or borrowed ([Path::indexed]).
+///
+/// The positions after each movement (the prefix sums of the movements) are computed
+/// once, along with an index of the first visit of each position, so that:
+///
+/// - [IndexedPath::position_at] and [IndexedPath::displacement] are `O(1)`,
+/// - [IndexedPath::first_visit] and [IndexedPath::visits] are `O(log n)`.
+///
+/// Building an `IndexedPath` of `n` movements is `O(n log n)`.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, IndexedPath, Offset, Path, Position};
+///
+/// let path = Path::new(vec![Balance::Right, Balance::Right, Balance::Top, Balance::BottomLeft]);
+/// let indexed = IndexedPath::new(path, Position::new(0, 0));
+/// assert_eq!(indexed.end(), Position::new(1, 0));
+/// assert_eq!(indexed.first_visit(Position::new(1, 0)), Some(1));
+/// assert_eq!(indexed.first_visit(Position::new(5, 5)), None);
+/// assert_eq!(indexed.displacement(1..3), Offset::new(1, -1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedPath<'a, T = i32> {
+    path: Cow<'a, Path>,
+    positions: Vec<Position<T>>,
+    first_visits: Vec<(Position<T>, usize)>,
+}
+
+impl<'a, T: Coordinate> IndexedPath<'a, T> {
+    /// Creates an `IndexedPath` following `path` from `start`, taking ownership of `path`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a coordinate overflows `T`.
+    pub fn new(path: Path, start: Position<T>) -> Self {
+        Self::from_cow(Cow::Owned(path), start)
+    }
+
+    /// Creates an `IndexedPath` following the owned or borrowed `path` from `start`.
+    fn from_cow(path: Cow<'a, Path>, start: Position<T>) -> Self {
+        let positions: Vec<Position<T>> = path.positions(start).collect();
+        let mut first_visits: Vec<(Position<T>, usize)> = positions
+            .iter()
+            .enumerate()
+            .map(|(step, &position)| (position, step))
+            .collect();
+        first_visits.sort_unstable();
+        first_visits.dedup_by_key(|(position, _)| *position);
+        Self {
+            path,
+            positions,
+            first_visits,
+        }
+    }
+
+    /// Returns the indexed `Path`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Consumes the `IndexedPath` and returns the indexed `Path`, cloned if it is borrowed.
+    pub fn into_path(self) -> Path {
+        self.path.into_owned()
+    }
+
+    /// Returns the number of movements of the `Path`.
+    pub fn len(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Checks whether the `Path` has no movement.
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Returns the position before the first movement.
+    pub fn start(&self) -> Position<T> {
+        self.positions[0]
+    }
+
+    /// Returns the position after the last movement.
+    pub fn end(&self) -> Position<T> {
+        self.positions[self.positions.len() - 1]
+    }
+
+    /// Returns the position after `step` movements, or `None` if `step > len()`.
+    pub fn position_at(&self, step: usize) -> Option<Position<T>> {
+        self.positions.get(step).copied()
+    }
+
+    /// Returns the visited positions, the start included (`len() + 1` positions).
+    pub fn positions(&self) -> &[Position<T>] {
+        &self.positions
+    }
+
+    /// Returns the number of movements after which `position` is visited for the first
+    /// time, or `None` if the `Path` never visits it.
+    pub fn first_visit(&self, position: Position<T>) -> Option<usize> {
+        self.first_visits
+            .binary_search_by(|(visited, _)| visited.cmp(&position))
+            .ok()
+            .map(|index| self.first_visits[index].1)
+    }
+
+    /// Checks whether the `Path` visits `position`.
+    pub fn visits(&self, position: Position<T>) -> bool {
+        self.first_visit(position).is_some()
+    }
+
+    /// Returns the displacement of the movements in `steps` (the sub-path `steps`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps.end > len()` or `steps.start > steps.end`, and, in debug builds,
+    /// if a component of the displacement overflows `T`.
+    pub fn displacement(&self, steps: Range<usize>) -> Offset<T> {
+        assert!(steps.start <= steps.end, "Invalid range");
+        self.positions[steps.end] - self.positions[steps.start]
+    }
+}
+
+impl<T: Coordinate> From<Path> for IndexedPath<'_, T> {
+    fn from(path: Path) -> Self {
+        Self::new(path, Position::ORIGIN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, IndexedPath, Path, Position};
+
+    #[test]
+    fn test_indexed_queries_match_scan() {
+        let movements = (0..500u32)
+            .map(|i| Balance::ALL[(i * i + 3 * i) as usize % 9])
+            .collect();
+        let path = Path::new(movements);
+        let start = Position::new(-3i64, 7);
+        let indexed = path.indexed(start);
+        let scanned: Vec<_> = path.positions(start).collect();
+        assert_eq!(indexed.positions(), &scanned[..]);
+        assert_eq!(path.positions(start).len(), path.len() + 1);
+        for (step, &position) in scanned.iter().enumerate() {
+            assert_eq!(indexed.position_at(step), Some(position));
+            let first = scanned.iter().position(|&p| p == position);
+            assert_eq!(indexed.first_visit(position), first);
+        }
+        assert_eq!(indexed.position_at(path.len() + 1), None);
+        assert_eq!(
+            indexed.displacement(0..path.len()),
+            path.displacement::<i64>()
+        );
+        let sub_path = Path::new(path.iter().copied().skip(100).take(50).collect());
+        assert_eq!(indexed.displacement(100..150), sub_path.displacement());
+        // The `Path` is borrowed, not copied.
+        assert!(core::ptr::eq(indexed.path(), &path));
+        assert_eq!(IndexedPath::<i64>::from(path).start(), Position::ORIGIN);
+    }
+}
@@ -0,0 +1,271 @@
+//! Serde representations of `Balance` and `Path` (requires the `serde` feature, and the
+//! `alloc` feature for `Path`).
+//!
+//! By default, a `Balance` uses the derived representation: its variant name (`"TopLeft"`) in
+//! self-describing formats such as JSON, but its variant index in compact binary formats such
+//! as bincode or postcard. A `Path` is serialized as a string of
+//! [Notation::Arrow](crate::Notation::Arrow) symbols (`"↑→↓"`) in human-readable formats, or
+//! as a sequence of [Balance::to_value] integers in binary formats.
+//!
+//! The submodules of this module select another representation with `#[serde(with = "...")]`:
+//!
+//! | Module           | Type      | Representation                  |
+//! |------------------|-----------|---------------------------------|
+//! | [balance_name]   | `Balance` | `"TopRight"`                    |
+//! | [balance_value]  | `Balance` | `-2`                            |
+//! | [balance_vector] | `Balance` | `[1, -1]`                       |
+//! | [path_symbols]   | `Path`    | `"↑→↓"`                         |
+//! | [path_values]    | `Path`    | `[-3, 1, 3]`                    |
+//!
+//! # Examples
+//!
+//! ```
+//! use balanced_direction::{Balance, Path};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Unit {
+//!     facing: Balance,
+//!     #[serde(with = "balanced_direction::serialization::balance_vector")]
+//!     aim: Balance,
+//!     #[serde(with = "balanced_direction::serialization::path_values")]
+//!     route: Path,
+//! }
+//!
+//! let unit = Unit {
+//!     facing: Balance::Top,
+//!     aim: Balance::TopRight,
+//!     route: Path::new(vec![Balance::Top, Balance::Right]),
+//! };
+//! let json = serde_json::to_string(&unit).unwrap();
+//! assert_eq!(json, r#"{"facing":"Top","aim":[1,-1],"route":[-3,1]}"#);
+//! assert_eq!(serde_json::from_str::<Unit>(&json).unwrap(), unit);
+//! ```
+
+#[cfg(feature = "alloc")]
+use crate::Path;
+use crate::{Balance, Notation};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+use core::fmt::Formatter;
+#[cfg(feature = "alloc")]
+use serde::de::SeqAccess;
+use serde::de::{Error, Visitor};
+#[cfg(feature = "alloc")]
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "alloc")]
+impl Serialize for Path {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            path_symbols::serialize(self, serializer)
+        } else {
+            path_values::serialize(self, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            path_symbols::deserialize(deserializer)
+        } else {
+            path_values::deserialize(deserializer)
+        }
+    }
+}
+
+/// Serializes a `Balance` as its variant name, as a string in every format.
+///
+/// Unlike the default representation, the name is also written in binary formats, where the
+/// derived implementation writes the variant index.
+pub mod balance_name {
+    use super::*;
+
+    struct NameVisitor;
+
+    impl Visitor<'_> for NameVisitor {
+        type Value = Balance;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("the name of a Balance variant")
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Balance, E> {
+            Balance::from_notation(value, Notation::Name).map_err(E::custom)
+        }
+    }
+
+    /// Serializes a `Balance` as the string of its variant name (`"TopRight"`).
+    pub fn serialize<S: Serializer>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(balance.to_notation(Notation::Name))
+    }
+
+    /// Deserializes a `Balance` from the string of its variant name (`"TopRight"`).
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+/// Serializes a `Balance` as its [Balance::to_value] integer.
+pub mod balance_value {
+    use super::*;
+
+    /// Serializes a `Balance` as an integer in the range `-4..=4`.
+    pub fn serialize<S: Serializer>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i8(balance.to_value())
+    }
+
+    /// Deserializes a `Balance` from an integer in the range `-4..=4`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+        let value = i8::deserialize(deserializer)?;
+        Balance::try_from_value(value).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes a `Balance` as its [Balance::to_vector] pair `[x, y]`.
+pub mod balance_vector {
+    use super::*;
+
+    /// Serializes a `Balance` as a pair of integers `[x, y]`.
+    pub fn serialize<S: Serializer>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error> {
+        balance.to_vector().serialize(serializer)
+    }
+
+    /// Deserializes a `Balance` from a pair of integers `[x, y]` in the range `-1..=1`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Balance, D::Error> {
+        let (x, y) = <(i8, i8)>::deserialize(deserializer)?;
+        Balance::try_from_vector(x, y).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes a `Path` as a string of [Notation::Arrow] symbols.
+#[cfg(feature = "alloc")]
+pub mod path_symbols {
+    use super::*;
+
+    struct Symbols<'a>(&'a Path);
+
+    impl Display for Symbols<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            for balance in self.0.iter() {
+                f.write_str(balance.to_notation(Notation::Arrow))?;
+            }
+            Ok(())
+        }
+    }
+
+    struct SymbolsVisitor;
+
+    impl Visitor<'_> for SymbolsVisitor {
+        type Value = Path;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("a string of arrow symbols")
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Path, E> {
+            let mut buffer = [0; 4];
+            value
+                .chars()
+                .map(|symbol| {
+                    Balance::from_notation(symbol.encode_utf8(&mut buffer), Notation::Arrow)
+                        .map_err(E::custom)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(Path::new)
+        }
+    }
+
+    /// Serializes a `Path` as a string of arrow symbols (`"↑→↓"`).
+    pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Symbols(path))
+    }
+
+    /// Deserializes a `Path` from a string of arrow symbols (`"↑→↓"`).
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Path, D::Error> {
+        deserializer.deserialize_str(SymbolsVisitor)
+    }
+}
+
+/// Serializes a `Path` as a sequence of [Balance::to_value] integers.
+#[cfg(feature = "alloc")]
+pub mod path_values {
+    use super::*;
+
+    struct ValuesVisitor;
+
+    impl<'de> Visitor<'de> for ValuesVisitor {
+        type Value = Path;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("a sequence of integers in the range -4..=4")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Path, A::Error> {
+            let mut movements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(value) = seq.next_element::<i8>()? {
+                movements.push(Balance::try_from_value(value).map_err(A::Error::custom)?);
+            }
+            Ok(Path::new(movements))
+        }
+    }
+
+    /// Serializes a `Path` as a sequence of integers (`[-3, 1, 3]`).
+    pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(path.len()))?;
+        for balance in path.iter() {
+            seq.serialize_element(&balance.to_value())?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a `Path` from a sequence of integers (`[-3, 1, 3]`).
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Path, D::Error> {
+        deserializer.deserialize_seq(ValuesVisitor)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Balance, Path};
+    use alloc::vec;
+
+    #[test]
+    fn test_default_representations() {
+        let path = Path::new(vec![Balance::Top, Balance::Center, Balance::BottomLeft]);
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(json, r#""↑·↙""#);
+        assert_eq!(serde_json::from_str::<Path>(&json).unwrap(), path);
+
+        let json = serde_json::to_string(&Balance::BottomRight).unwrap();
+        assert_eq!(json, r#""BottomRight""#);
+        assert_eq!(
+            serde_json::from_str::<Balance>(&json).unwrap(),
+            Balance::BottomRight
+        );
+    }
+
+    #[test]
+    fn test_balance_name() {
+        use super::balance_name;
+
+        for balance in Balance::ALL {
+            let json = balance_name::serialize(&balance, serde_json::value::Serializer).unwrap();
+            assert_eq!(json, balance.to_notation(crate::Notation::Name));
+            assert_eq!(balance_name::deserialize(json).ok(), Some(balance));
+        }
+        assert!(balance_name::deserialize(serde_json::json!(8)).is_err());
+        assert!(balance_name::deserialize(serde_json::json!("Up")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(serde_json::from_str::<Path>(r#""↑x""#).is_err());
+        assert!(serde_json::from_str::<Balance>(r#""Up""#).is_err());
+    }
+}
@@ -0,0 +1,402 @@
+use crate::{Balance, UnaryOp};
+use core::fmt::{Debug, Formatter};
+use core::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+/// Builds a `BalanceSet` with the `Balance` variants satisfying a (const) predicate.
+macro_rules! select {
+    ($b:ident => $e:expr) => {
+        select!(@ $b => $e; TopLeft, Top, TopRight, Left, Center, Right, BottomLeft, Bottom, BottomRight)
+    };
+    (@ $b:ident => $e:expr; $($variant:ident),*) => {
+        BalanceSet::EMPTY$(.with_if(Balance::$variant, {
+            let $b = Balance::$variant;
+            $e
+        }))*
+    };
+}
+
+/// A set of `Balance` variants, stored as a 9-bit mask in a `u16`.
+///
+/// A `BalanceSet` describes a subset of the cells of the 3x3 grid, such as a neighbourhood
+/// mask or a wall mask. Constants are provided for the subsets described by the predicates
+/// of `Balance` ([BalanceSet::TOP_ROW] for [Balance::has_top], [BalanceSet::CORNERS] for
+/// [Balance::is_corner], ...). The usual set operations are available as methods and as
+/// operators (`|`, `&`, `-`, `^` and `!`).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, BalanceSet};
+///
+/// let walls: BalanceSet = [Balance::Top, Balance::TopRight, Balance::Right].into_iter().collect();
+/// let open = BalanceSet::EDGES - walls;
+/// assert_eq!(open.len(), 2);
+/// assert!(open.contains(Balance::Left));
+/// assert!(!open.contains(Balance::Top));
+/// assert_eq!(walls & BalanceSet::CORNERS, BalanceSet::from(Balance::TopRight));
+/// ```
+#[derive(PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub struct BalanceSet {
+    bits: u16,
+}
+
+impl BalanceSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self { bits: 0 };
+    /// The set of the nine `Balance` variants.
+    pub const FULL: Self = Self { bits: 0x1FF };
+    /// The top row (see [Balance::has_top]).
+    pub const TOP_ROW: Self = select!(b => b.has_top());
+    /// The bottom row (see [Balance::has_bottom]).
+    pub const BOTTOM_ROW: Self = select!(b => b.has_bottom());
+    /// The left column (see [Balance::has_left]).
+    pub const LEFT_COLUMN: Self = select!(b => b.has_left());
+    /// The right column (see [Balance::has_right]).
+    pub const RIGHT_COLUMN: Self = select!(b => b.has_right());
+    /// The center and the edges (see [Balance::is_orthogonal]).
+    pub const ORTHOGONAL: Self = select!(b => b.is_orthogonal());
+    /// The center and the corners (see [Balance::is_diagonal]).
+    pub const DIAGONAL: Self = select!(b => b.is_diagonal());
+    /// The four edges (see [Balance::is_edge]).
+    pub const EDGES: Self = select!(b => b.is_edge());
+    /// The four corners (see [Balance::is_corner]).
+    pub const CORNERS: Self = select!(b => b.is_corner());
+    /// The eight directions (every variant but `Balance::Center`).
+    pub const DIRECTIONS: Self = select!(b => !matches!(b, Balance::Center));
+    /// See [Balance::is_true].
+    #[cfg(feature = "ternary")]
+    pub const TRUE: Self = select!(b => b.is_true());
+    /// See [Balance::has_true].
+    #[cfg(feature = "ternary")]
+    pub const HAS_TRUE: Self = select!(b => b.has_true());
+    /// See [Balance::is_false].
+    #[cfg(feature = "ternary")]
+    pub const FALSE: Self = select!(b => b.is_false());
+    /// See [Balance::has_false].
+    #[cfg(feature = "ternary")]
+    pub const HAS_FALSE: Self = select!(b => b.has_false());
+    /// See [Balance::is_contradictory].
+    #[cfg(feature = "ternary")]
+    pub const CONTRADICTORY: Self = select!(b => b.is_contradictory());
+    /// See [Balance::has_unknown].
+    #[cfg(feature = "ternary")]
+    pub const HAS_UNKNOWN: Self = select!(b => b.has_unknown());
+    /// See [Balance::is_certain].
+    #[cfg(feature = "ternary")]
+    pub const CERTAIN: Self = select!(b => b.is_certain());
+    /// See [Balance::is_uncertain].
+    #[cfg(feature = "ternary")]
+    pub const UNCERTAIN: Self = select!(b => b.is_uncertain());
+
+    /// Creates a `BalanceSet` from a bit mask, where the bit `i` stands for the `Balance`
+    /// of value `i - 4` (see [Balance::to_value]). Bits above the ninth are ignored.
+    pub const fn from_bits(bits: u16) -> Self {
+        Self {
+            bits: bits & Self::FULL.bits,
+        }
+    }
+
+    /// Returns the bit mask of the set (see [BalanceSet::from_bits]).
+    pub const fn bits(self) -> u16 {
+        self.bits
+    }
+
+    /// Returns the bit of a `Balance` in the mask.
+    const fn bit(balance: Balance) -> u16 {
+        1 << balance.index()
+    }
+
+    /// Checks if the set contains the given `Balance`.
+    pub const fn contains(self, balance: Balance) -> bool {
+        self.bits & Self::bit(balance) != 0
+    }
+
+    /// Returns the number of `Balance` variants in the set.
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Checks if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns a copy of the set containing `balance`.
+    pub const fn with(self, balance: Balance) -> Self {
+        Self {
+            bits: self.bits | Self::bit(balance),
+        }
+    }
+
+    /// Returns a copy of the set not containing `balance`.
+    pub const fn without(self, balance: Balance) -> Self {
+        Self {
+            bits: self.bits & !Self::bit(balance),
+        }
+    }
+
+    /// Returns a copy of the set containing `balance` if `condition` is `true`.
+    const fn with_if(self, balance: Balance, condition: bool) -> Self {
+        if condition {
+            self.with(balance)
+        } else {
+            self
+        }
+    }
+
+    /// Adds a `Balance` to the set. Returns whether it was not already present.
+    pub fn insert(&mut self, balance: Balance) -> bool {
+        let absent = !self.contains(balance);
+        *self = self.with(balance);
+        absent
+    }
+
+    /// Removes a `Balance` from the set. Returns whether it was present.
+    pub fn remove(&mut self, balance: Balance) -> bool {
+        let present = self.contains(balance);
+        *self = self.without(balance);
+        present
+    }
+
+    /// Returns the `Balance` variants in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Returns the `Balance` variants in both sets.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Returns the `Balance` variants in this set but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Returns the `Balance` variants in exactly one of the sets.
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits ^ other.bits,
+        }
+    }
+
+    /// Returns the `Balance` variants not in this set.
+    pub const fn complement(self) -> Self {
+        Self::FULL.difference(self)
+    }
+
+    /// Checks if every `Balance` of this set is in `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    /// Checks if the sets have no `Balance` in common.
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.bits & other.bits == 0
+    }
+
+    /// Returns an iterator over the `Balance` variants of the set, in the order of [Balance::to_value].
+    pub const fn iter(self) -> BalanceSetIter {
+        BalanceSetIter { bits: self.bits }
+    }
+
+    /// Returns the image of the set under `f`: the set of `f(b)` for each `b` in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceSet};
+    ///
+    /// assert_eq!(BalanceSet::TOP_ROW.map(Balance::rotate_left), BalanceSet::LEFT_COLUMN);
+    /// assert_eq!(BalanceSet::TOP_ROW.map(Balance::center_h), BalanceSet::from(Balance::Top));
+    /// ```
+    pub fn map(self, f: impl Fn(Balance) -> Balance) -> Self {
+        self.iter().map(f).collect()
+    }
+
+    /// Returns the image of the set under a `UnaryOp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{BalanceSet, UnaryOp};
+    ///
+    /// assert_eq!(BalanceSet::LEFT_COLUMN.image(UnaryOp::FLIP_H), BalanceSet::RIGHT_COLUMN);
+    /// ```
+    pub const fn image(self, op: UnaryOp) -> Self {
+        let mut result = Self::EMPTY;
+        let mut i = 0;
+        while i < 9 {
+            if self.contains(Balance::ALL[i]) {
+                result = result.with(op.apply(Balance::ALL[i]));
+            }
+            i += 1;
+        }
+        result
+    }
+}
+
+impl Debug for BalanceSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Balance> for BalanceSet {
+    fn from(balance: Balance) -> Self {
+        Self::EMPTY.with(balance)
+    }
+}
+
+impl FromIterator<Balance> for BalanceSet {
+    fn from_iter<T: IntoIterator<Item = Balance>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Balance> for BalanceSet {
+    fn extend<T: IntoIterator<Item = Balance>>(&mut self, iter: T) {
+        for balance in iter {
+            self.insert(balance);
+        }
+    }
+}
+
+impl IntoIterator for BalanceSet {
+    type Item = Balance;
+    type IntoIter = BalanceSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BalanceSetIter { bits: self.bits }
+    }
+}
+
+/// An iterator over the `Balance` variants of a [BalanceSet], in the order of [Balance::to_value].
+///
+/// This `struct` is created by [BalanceSet::iter].
+#[derive(Debug, Clone)]
+pub struct BalanceSetIter {
+    bits: u16,
+}
+
+impl Iterator for BalanceSetIter {
+    type Item = Balance;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let i = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(Balance::ALL[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for BalanceSetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let i = 15 - self.bits.leading_zeros() as usize;
+        self.bits &= !(1 << i);
+        Some(Balance::ALL[i])
+    }
+}
+
+impl ExactSizeIterator for BalanceSetIter {}
+
+impl core::iter::FusedIterator for BalanceSetIter {}
+
+impl BitOr for BalanceSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for BalanceSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitXor for BalanceSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl Sub for BalanceSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl Not for BalanceSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, BalanceSet, UnaryOp};
+
+    #[test]
+    fn test_constants_match_predicates() {
+        let select = |predicate: fn(&Balance) -> bool| {
+            Balance::ALL
+                .into_iter()
+                .filter(predicate)
+                .collect::<BalanceSet>()
+        };
+        assert_eq!(BalanceSet::TOP_ROW, select(|b| b.has_top()));
+        assert_eq!(BalanceSet::BOTTOM_ROW, select(|b| b.has_bottom()));
+        assert_eq!(BalanceSet::LEFT_COLUMN, select(|b| b.has_left()));
+        assert_eq!(BalanceSet::RIGHT_COLUMN, select(|b| b.has_right()));
+        assert_eq!(BalanceSet::ORTHOGONAL, select(|b| b.is_orthogonal()));
+        assert_eq!(BalanceSet::DIAGONAL, select(|b| b.is_diagonal()));
+        assert_eq!(BalanceSet::EDGES, select(|b| b.is_edge()));
+        assert_eq!(BalanceSet::CORNERS, select(|b| b.is_corner()));
+        assert_eq!(BalanceSet::ORTHOGONAL.len(), 5);
+        assert_eq!(BalanceSet::CORNERS.len(), 4);
+    }
+
+    #[test]
+    fn test_iteration_and_collection() {
+        for bits in 0..0x200 {
+            let set = BalanceSet::from_bits(bits);
+            assert_eq!(set.iter().len(), set.len());
+            assert_eq!(set.iter().collect::<BalanceSet>(), set);
+            assert_eq!(set.iter().rev().collect::<BalanceSet>(), set);
+            assert_eq!(set | !set, BalanceSet::FULL);
+            assert!((set & !set).is_empty());
+            assert_eq!(
+                set.image(UnaryOp::ROTATE_LEFT),
+                set.map(Balance::rotate_left)
+            );
+        }
+    }
+}
@@ -0,0 +1,397 @@
+#[cfg(feature = "alloc")]
+use crate::Path;
+use crate::{Balance, BinaryOp, Coordinate, Offset, Position};
+use core::iter::{FusedIterator, Map, Peekable};
+
+/// Lazy adaptors and consumers for any iterator of movements.
+///
+/// `Steps` is implemented for every `Iterator<Item = Balance>`, so that transformations can
+/// be chained on a route without allocating an intermediate `Path` at each step: the
+/// movements are only computed when the iterator is consumed, by a fold
+/// ([Steps::displacement], [Steps::fold_op], ...) or a collection ([Steps::collect_path],
+/// `collect::<ArrayPath<N>>()`, ...).
+///
+/// The transformations of `Path` (`each`, `each_with`, `each_zip`, `normalized`...) are
+/// built on these adaptors.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Offset, Steps};
+///
+/// let replay = core::iter::repeat(Balance::Top).take(1000);
+/// let offset = replay
+///     .map_balance(Balance::rotate_right)
+///     .zip_with(core::ops::Add::add, core::iter::repeat(Balance::Bottom))
+///     .displacement::<i32>();
+/// assert_eq!(offset, Offset::new(1000, 1000));
+/// ```
+pub trait Steps: Iterator<Item = Balance> + Sized {
+    /// Applies `f` to each movement, lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{ArrayPath, Balance, Steps};
+    ///
+    /// let route = [Balance::Top, Balance::Right];
+    /// let rotated: ArrayPath<2> = route.into_iter().map_balance(Balance::rotate_left).collect();
+    /// assert_eq!(rotated.as_slice(), &[Balance::Left, Balance::Top]);
+    /// ```
+    fn map_balance<F: FnMut(Balance) -> Balance>(self, f: F) -> Map<Self, F> {
+        self.map(f)
+    }
+
+    /// Applies `f` to the pairs of movements of this iterator and `other`, lazily. The
+    /// iteration stops at the end of the shorter one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Steps};
+    /// use core::ops::Add;
+    ///
+    /// let a = [Balance::Top, Balance::Right, Balance::Left];
+    /// let b = [Balance::Right, Balance::Left];
+    /// let mut sums = a.into_iter().zip_with(Balance::add, b);
+    /// assert_eq!(sums.next(), Some(Balance::TopRight));
+    /// assert_eq!(sums.next(), Some(Balance::Center));
+    /// assert_eq!(sums.next(), None);
+    /// ```
+    fn zip_with<J, F>(self, f: F, other: J) -> ZipWith<Self, J::IntoIter, F>
+    where
+        J: IntoIterator<Item = Balance>,
+        F: FnMut(Balance, Balance) -> Balance,
+    {
+        ZipWith {
+            a: self,
+            b: other.into_iter(),
+            f,
+        }
+    }
+
+    /// Returns the cumulative movement of the remaining movements as an [Offset] with
+    /// coordinates of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if a component of the sum overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Offset, Steps};
+    ///
+    /// let steps = core::iter::repeat(Balance::BottomLeft).take(200);
+    /// assert_eq!(steps.displacement::<i16>(), Offset::new(-200, 200));
+    /// ```
+    fn displacement<T: Coordinate>(self) -> Offset<T> {
+        self.fold(Offset::ZERO, |offset, movement| offset + movement)
+    }
+
+    /// Returns an iterator over the positions visited from `start`, the start included.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, the iterator panics if a coordinate overflows `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Position, Steps};
+    ///
+    /// let mut positions = [Balance::Right, Balance::Top].into_iter().positions(Position::new(0, 0));
+    /// assert_eq!(positions.next(), Some(Position::new(0, 0)));
+    /// assert_eq!(positions.next(), Some(Position::new(1, 0)));
+    /// assert_eq!(positions.next(), Some(Position::new(1, -1)));
+    /// assert_eq!(positions.next(), None);
+    /// ```
+    fn positions<T: Coordinate>(self, start: Position<T>) -> Positions<Self, T> {
+        Positions {
+            movements: self,
+            next: Some(start),
+        }
+    }
+
+    /// Skips each movement immediately followed by its opposite, lazily.
+    ///
+    /// Unlike [Path::reduced](crate::Path::reduced), which needs to remember every kept
+    /// movement, the cancellations do not cascade: a backtrack is only removed when its two
+    /// movements are adjacent in the original route. `Balance::Center` movements are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Steps};
+    ///
+    /// let route = [Balance::Top, Balance::Right, Balance::Left, Balance::TopLeft];
+    /// let mut reduced = route.into_iter().reduce_backtracks();
+    /// assert_eq!(reduced.next(), Some(Balance::Top));
+    /// assert_eq!(reduced.next(), Some(Balance::TopLeft));
+    /// assert_eq!(reduced.next(), None);
+    ///
+    /// // The cancellation of `Right, Left` does not bring `Top` and `Bottom` together.
+    /// let route = [Balance::Top, Balance::Right, Balance::Left, Balance::Bottom];
+    /// assert_eq!(route.into_iter().reduce_backtracks().count(), 2);
+    /// ```
+    fn reduce_backtracks(self) -> ReduceBacktracks<Self> {
+        ReduceBacktracks {
+            movements: self.peekable(),
+        }
+    }
+
+    /// Folds the movements with a [BinaryOp], starting from `init`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp, Steps};
+    ///
+    /// let route = [Balance::Left, Balance::Top, Balance::Top];
+    /// assert_eq!(route.into_iter().fold_op(Balance::Center, &BinaryOp::ADD), Balance::TopLeft);
+    /// ```
+    fn fold_op(self, init: Balance, op: &BinaryOp) -> Balance {
+        self.fold(init, |accumulator, movement| {
+            op.apply(accumulator, movement)
+        })
+    }
+
+    /// Returns the conjunction ([Balance::bitand](core::ops::BitAnd::bitand)) of the
+    /// movements, `Balance::BottomRight` (True, True) if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Steps};
+    ///
+    /// let states = [Balance::BottomRight, Balance::Right, Balance::Bottom];
+    /// assert_eq!(states.into_iter().fold_and(), Balance::Center);
+    /// assert_eq!(core::iter::empty().fold_and(), Balance::BottomRight);
+    /// ```
+    #[cfg(feature = "ternary")]
+    fn fold_and(self) -> Balance {
+        self.fold(Balance::BottomRight, |accumulator, movement| {
+            accumulator & movement
+        })
+    }
+
+    /// Returns the disjunction ([Balance::bitor](core::ops::BitOr::bitor)) of the movements,
+    /// `Balance::TopLeft` (False, False) if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Steps};
+    ///
+    /// let states = [Balance::TopLeft, Balance::Right, Balance::Bottom];
+    /// assert_eq!(states.into_iter().fold_or(), Balance::BottomRight);
+    /// ```
+    #[cfg(feature = "ternary")]
+    fn fold_or(self) -> Balance {
+        self.fold(Balance::TopLeft, |accumulator, movement| {
+            accumulator | movement
+        })
+    }
+
+    /// Returns the exclusive disjunction ([Balance::bitxor](core::ops::BitXor::bitxor)) of
+    /// the movements, `Balance::TopLeft` (False, False) if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Steps};
+    ///
+    /// let states = [Balance::BottomRight, Balance::TopRight];
+    /// assert_eq!(states.into_iter().fold_xor(), Balance::BottomLeft);
+    /// ```
+    #[cfg(feature = "ternary")]
+    fn fold_xor(self) -> Balance {
+        self.fold(Balance::TopLeft, |accumulator, movement| {
+            accumulator ^ movement
+        })
+    }
+
+    /// Collects the movements into a `Path`.
+    #[cfg(feature = "alloc")]
+    fn collect_path(self) -> Path {
+        Path::new(self.collect())
+    }
+}
+
+impl<I: Iterator<Item = Balance>> Steps for I {}
+
+/// An iterator applying a function to the pairs of movements of two iterators.
+///
+/// This `struct` is created by [Steps::zip_with].
+#[derive(Debug, Clone)]
+pub struct ZipWith<A, B, F> {
+    a: A,
+    b: B,
+    f: F,
+}
+
+impl<A, B, F> Iterator for ZipWith<A, B, F>
+where
+    A: Iterator<Item = Balance>,
+    B: Iterator<Item = Balance>,
+    F: FnMut(Balance, Balance) -> Balance,
+{
+    type Item = Balance;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        Some((self.f)(a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_min, a_max) = self.a.size_hint();
+        let (b_min, b_max) = self.b.size_hint();
+        let max = match (a_max, b_max) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (a_min.min(b_min), max)
+    }
+}
+
+impl<A, B, F> ExactSizeIterator for ZipWith<A, B, F>
+where
+    A: ExactSizeIterator<Item = Balance>,
+    B: ExactSizeIterator<Item = Balance>,
+    F: FnMut(Balance, Balance) -> Balance,
+{
+}
+
+/// An iterator over the positions visited by a sequence of movements, the start included.
+///
+/// This `struct` is created by [Steps::positions] (or `Path::positions`). The positions are
+/// computed lazily, one movement at a time.
+#[derive(Debug, Clone)]
+pub struct Positions<I, T = i32> {
+    movements: I,
+    next: Option<Position<T>>,
+}
+
+impl<I: Iterator<Item = Balance>, T: Coordinate> Iterator for Positions<I, T> {
+    type Item = Position<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = self.movements.next().map(|movement| current + movement);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => {
+                let (min, max) = self.movements.size_hint();
+                (
+                    min.saturating_add(1),
+                    max.and_then(|max| max.checked_add(1)),
+                )
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<I: ExactSizeIterator<Item = Balance>, T: Coordinate> ExactSizeIterator for Positions<I, T> {}
+
+impl<I: Iterator<Item = Balance>, T: Coordinate> FusedIterator for Positions<I, T> {}
+
+/// An iterator skipping the movements immediately followed by their opposite.
+///
+/// This `struct` is created by [Steps::reduce_backtracks].
+#[derive(Debug, Clone)]
+pub struct ReduceBacktracks<I: Iterator<Item = Balance>> {
+    movements: Peekable<I>,
+}
+
+impl<I: Iterator<Item = Balance>> Iterator for ReduceBacktracks<I> {
+    type Item = Balance;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let movement = self.movements.next()?;
+            if movement != Balance::Center && self.movements.next_if_eq(&-movement).is_some() {
+                continue;
+            }
+            return Some(movement);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every movement may be cancelled by the next one.
+        (0, self.movements.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator<Item = Balance>> FusedIterator for ReduceBacktracks<I> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{Balance, Path, Position, Steps};
+
+    #[test]
+    fn test_adaptors_match_path() {
+        let path: Path = (0..200u32)
+            .map(|i| Balance::ALL[(i * i + 7 * i) as usize % 9])
+            .collect();
+        let start = Position::new(5i64, -5);
+        assert_eq!(
+            path.as_path_slice().steps().displacement::<i64>(),
+            path.displacement::<i64>()
+        );
+        assert!(path
+            .iter()
+            .copied()
+            .positions(start)
+            .eq(path.positions(start)));
+        assert_eq!(
+            path.as_path_slice().steps().positions(start).len(),
+            path.len() + 1
+        );
+
+        let reduced = path
+            .as_path_slice()
+            .steps()
+            .reduce_backtracks()
+            .collect_path();
+        assert_eq!(reduced.displacement::<i64>(), path.displacement::<i64>());
+        assert!(reduced.len() <= path.len());
+        assert!(reduced.reduced().len() <= reduced.len());
+        assert_eq!(reduced.reduced(), path.reduced());
+
+        let zipped = path
+            .as_path_slice()
+            .steps()
+            .zip_with(core::ops::Add::add, path.reversed());
+        assert_eq!(zipped.len(), path.len());
+        assert_eq!(
+            zipped.collect_path(),
+            path.each_zip(core::ops::Add::add, &path.reversed())
+        );
+    }
+
+    #[test]
+    fn test_reduce_backtracks_size_hint() {
+        let inputs: [&[Balance]; 4] = [
+            &[Balance::Top, Balance::Bottom],
+            &[Balance::Left, Balance::Right, Balance::Top],
+            &[Balance::Center, Balance::Center],
+            &[Balance::Top, Balance::Top, Balance::Bottom, Balance::Bottom],
+        ];
+        for movements in inputs {
+            let mut reduced = movements.iter().copied().reduce_backtracks();
+            loop {
+                let (min, max) = reduced.size_hint();
+                let remaining = reduced.clone().count();
+                assert!(min <= remaining && max.is_none_or(|max| remaining <= max));
+                if reduced.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::Balance;
+#[cfg(feature = "alloc")]
+use crate::Path;
+
+/// The composition table of the symmetries: `CAYLEY[a][b]` is `a` followed by `b`.
+#[rustfmt::skip]
+const CAYLEY: [[Symmetry; 8]; 8] = {
+    use Symmetry::*;
+    [
+        [Identity,       RotateLeft,     Rotate180,      RotateRight,    FlipH,          FlipV,          Transpose,      AntiTranspose],
+        [RotateLeft,     Rotate180,      RotateRight,    Identity,       AntiTranspose,  Transpose,      FlipH,          FlipV],
+        [Rotate180,      RotateRight,    Identity,       RotateLeft,     FlipV,          FlipH,          AntiTranspose,  Transpose],
+        [RotateRight,    Identity,       RotateLeft,     Rotate180,      Transpose,      AntiTranspose,  FlipV,          FlipH],
+        [FlipH,          Transpose,      FlipV,          AntiTranspose,  Identity,       Rotate180,      RotateLeft,     RotateRight],
+        [FlipV,          AntiTranspose,  FlipH,          Transpose,      Rotate180,      Identity,       RotateRight,    RotateLeft],
+        [Transpose,      FlipV,          AntiTranspose,  FlipH,          RotateRight,    RotateLeft,     Identity,       Rotate180],
+        [AntiTranspose,  FlipH,          Transpose,      FlipV,          RotateLeft,     RotateRight,    Rotate180,      Identity],
+    ]
+};
+
+/// An element of the dihedral group D4: one of the eight symmetries of the 3x3 grid.
+///
+/// A `Symmetry` names one of the existing transformations of a `Balance`
+/// ([Balance::rotate_left], [Balance::flip_h], `!` (transpose), ...) so that it can be stored,
+/// composed ([Symmetry::compose]) or inverted ([Symmetry::inverse]), and applied to a whole
+/// `Path` ([Symmetry::apply_path]).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Symmetry};
+///
+/// let symmetry = Symmetry::RotateLeft.compose(Symmetry::FlipH);
+/// assert_eq!(symmetry, Symmetry::AntiTranspose);
+/// assert_eq!(symmetry.apply(Balance::TopRight), Balance::TopRight);
+/// assert_eq!(symmetry.apply(Balance::Top), Balance::Right);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Symmetry {
+    /// Leaves every position unchanged.
+    Identity,
+    /// Rotates 90 degrees counterclockwise (see [Balance::rotate_left]).
+    RotateLeft,
+    /// Rotates 180 degrees (see [Balance::neg](core::ops::Neg::neg)).
+    Rotate180,
+    /// Rotates 90 degrees clockwise (see [Balance::rotate_right]).
+    RotateRight,
+    /// Mirrors across the vertical axis (see [Balance::flip_h]).
+    FlipH,
+    /// Mirrors across the horizontal axis (see [Balance::flip_v]).
+    FlipV,
+    /// Mirrors across the `TopLeft`-`BottomRight` diagonal (see [Balance::not](core::ops::Not::not)).
+    Transpose,
+    /// Mirrors across the `TopRight`-`BottomLeft` diagonal.
+    AntiTranspose,
+}
+
+impl Symmetry {
+    /// All the symmetries: the four rotations followed by the four reflections.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateLeft,
+        Symmetry::Rotate180,
+        Symmetry::RotateRight,
+        Symmetry::FlipH,
+        Symmetry::FlipV,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// Returns the `Symmetry` equivalent to this `Symmetry` followed by `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Symmetry};
+    ///
+    /// assert_eq!(Symmetry::FlipH.compose(Symmetry::FlipV), Symmetry::Rotate180);
+    /// assert_eq!(Symmetry::RotateLeft.compose(Symmetry::RotateLeft), Symmetry::Rotate180);
+    ///
+    /// let balance = Balance::TopRight;
+    /// let composed = Symmetry::Transpose.compose(Symmetry::RotateLeft);
+    /// assert_eq!(
+    ///     composed.apply(balance),
+    ///     Symmetry::RotateLeft.apply(Symmetry::Transpose.apply(balance))
+    /// );
+    /// ```
+    pub const fn compose(self, other: Self) -> Self {
+        CAYLEY[self as usize][other as usize]
+    }
+
+    /// Returns the `Symmetry` which cancels this one.
+    ///
+    /// Every reflection, as well as `Rotate180`, is its own inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Symmetry;
+    ///
+    /// assert_eq!(Symmetry::RotateLeft.inverse(), Symmetry::RotateRight);
+    /// assert_eq!(Symmetry::Transpose.inverse(), Symmetry::Transpose);
+    /// ```
+    pub const fn inverse(self) -> Self {
+        match self {
+            Symmetry::RotateLeft => Symmetry::RotateRight,
+            Symmetry::RotateRight => Symmetry::RotateLeft,
+            _ => self,
+        }
+    }
+
+    /// Checks if the current `Symmetry` is a rotation (including `Identity`).
+    pub const fn is_rotation(self) -> bool {
+        matches!(
+            self,
+            Symmetry::Identity | Symmetry::RotateLeft | Symmetry::Rotate180 | Symmetry::RotateRight
+        )
+    }
+
+    /// Checks if the current `Symmetry` is a reflection.
+    pub const fn is_reflection(self) -> bool {
+        !self.is_rotation()
+    }
+
+    /// Applies the current `Symmetry` to a `Balance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Symmetry};
+    ///
+    /// assert_eq!(Symmetry::RotateLeft.apply(Balance::Right), Balance::Top);
+    /// assert_eq!(Symmetry::AntiTranspose.apply(Balance::Right), Balance::Top);
+    /// assert_eq!(Symmetry::FlipV.apply(Balance::Center), Balance::Center);
+    /// ```
+    pub const fn apply(self, balance: Balance) -> Balance {
+        // `-` and `!` are not `const`: the half-turn and the transpositions are composed from
+        // the quarter-turns and the flips.
+        match self {
+            Symmetry::Identity => balance,
+            Symmetry::RotateLeft => balance.rotate_left(),
+            Symmetry::Rotate180 => balance.rotate_left().rotate_left(),
+            Symmetry::RotateRight => balance.rotate_right(),
+            Symmetry::FlipH => balance.flip_h(),
+            Symmetry::FlipV => balance.flip_v(),
+            Symmetry::Transpose => balance.rotate_left().flip_v(),
+            Symmetry::AntiTranspose => balance.rotate_left().flip_h(),
+        }
+    }
+
+    /// Applies the current `Symmetry` to each step of a `Path`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Path, Symmetry};
+    ///
+    /// let path = Path::new(vec![Balance::Right, Balance::Right, Balance::TopRight]);
+    /// let transformed = Symmetry::RotateRight.apply_path(&path);
+    /// assert_eq!(transformed.to_vector(), (1, 3));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn apply_path(self, path: &Path) -> Path {
+        path.each(|balance| self.apply(balance))
+    }
+
+    /// Returns an iterator over all the symmetries mapping `from` onto `to`.
+    ///
+    /// There are two such symmetries between two corners or two edges (one rotation and one
+    /// reflection), eight for `Balance::Center` onto itself, and none otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Symmetry};
+    ///
+    /// let symmetries: Vec<Symmetry> = Symmetry::between(Balance::Top, Balance::Right).collect();
+    /// assert_eq!(symmetries, vec![Symmetry::RotateRight, Symmetry::AntiTranspose]);
+    ///
+    /// assert_eq!(Symmetry::between(Balance::Top, Balance::TopRight).count(), 0);
+    /// ```
+    pub fn between(from: Balance, to: Balance) -> impl Iterator<Item = Symmetry> {
+        Self::ALL
+            .into_iter()
+            .filter(move |symmetry| symmetry.apply(from) == to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Symmetry};
+
+    #[test]
+    fn test_cayley_table() {
+        for a in Symmetry::ALL {
+            assert_eq!(a.compose(a.inverse()), Symmetry::Identity);
+            for b in Symmetry::ALL {
+                for balance in Balance::ALL {
+                    assert_eq!(a.compose(b).apply(balance), b.apply(a.apply(balance)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_operations() {
+        for balance in Balance::ALL {
+            assert_eq!(Symmetry::RotateLeft.apply(balance), balance.rotate_left());
+            assert_eq!(Symmetry::Rotate180.apply(balance), -balance);
+            assert_eq!(Symmetry::RotateRight.apply(balance), balance.rotate_right());
+            assert_eq!(Symmetry::FlipH.apply(balance), balance.flip_h());
+            assert_eq!(Symmetry::FlipV.apply(balance), balance.flip_v());
+            assert_eq!(Symmetry::Transpose.apply(balance), !balance);
+            assert_eq!(Symmetry::AntiTranspose.apply(balance), -!balance);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Balance;
+use crate::{Balance, BalanceError};
 use balanced_ternary::Digit;
 use core::ops::{BitAnd, BitOr, BitXor};
 
@@ -181,7 +181,8 @@ impl Balance {
     /// Panics if the `Balance` logical state is uncertain. Uncertain states include cases
     /// where the position cannot be determined or does not logically map to `true` or `false`.
     ///
-    /// > Use [Balance::is_certain] to check certainty of the `Balance` logical state.
+    /// > Use [Balance::is_certain] to check certainty of the `Balance` logical state,
+    /// > or [Balance::try_to_bool] for a non-panicking conversion.
     ///
     /// # Examples
     ///
@@ -199,12 +200,36 @@ impl Balance {
     /// // balance.to_bool();
     /// ```
     pub const fn to_bool(self) -> bool {
-        self.is_true() || {
-            if self.is_false() {
-                false
-            } else {
-                panic!("Cannot convert an uncertain Balance to a boolean value.")
-            }
+        match self.try_to_bool() {
+            Ok(value) => value,
+            Err(_) => panic!("Cannot convert an uncertain Balance to a boolean value."),
+        }
+    }
+
+    /// Converts the `Balance` logical state into a boolean representation without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::to_bool].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::Uncertain] if the `Balance` logical state is uncertain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError};
+    ///
+    /// assert_eq!(Balance::BottomRight.try_to_bool(), Ok(true));
+    /// assert_eq!(Balance::TopLeft.try_to_bool(), Ok(false));
+    /// assert_eq!(Balance::Center.try_to_bool(), Err(BalanceError::Uncertain));
+    /// ```
+    pub const fn try_to_bool(self) -> Result<bool, BalanceError> {
+        if self.is_true() {
+            Ok(true)
+        } else if self.is_false() {
+            Ok(false)
+        } else {
+            Err(BalanceError::Uncertain)
         }
     }
 
@@ -237,12 +262,24 @@ impl Balance {
     /// // balance.x_to_bool();
     /// ```
     pub const fn x_to_bool(self) -> bool {
-        self.x() == 1 || {
-            if self.x() == -1 {
-                false
-            } else {
-                panic!("Cannot convert an unknown-x Balance to a boolean value.")
-            }
+        match self.try_x_to_bool() {
+            Ok(value) => value,
+            Err(_) => panic!("Cannot convert an unknown-x Balance to a boolean value."),
+        }
+    }
+
+    /// Converts the x-coordinate of the `Balance` position into a boolean representation without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::x_to_bool].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::Uncertain] if the x-coordinate is unknown (i.e., `0`).
+    pub const fn try_x_to_bool(self) -> Result<bool, BalanceError> {
+        match self.x() {
+            1 => Ok(true),
+            -1 => Ok(false),
+            _ => Err(BalanceError::Uncertain),
         }
     }
 
@@ -275,12 +312,24 @@ impl Balance {
     /// // balance.y_to_bool();
     /// ```
     pub const fn y_to_bool(self) -> bool {
-        self.y() == 1 || {
-            if self.y() == -1 {
-                false
-            } else {
-                panic!("Cannot convert an unknown-y Balance to a boolean value.")
-            }
+        match self.try_y_to_bool() {
+            Ok(value) => value,
+            Err(_) => panic!("Cannot convert an unknown-y Balance to a boolean value."),
+        }
+    }
+
+    /// Converts the y-coordinate of the `Balance` position into a boolean representation without panicking.
+    ///
+    /// This is the fallible counterpart of [Balance::y_to_bool].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::Uncertain] if the y-coordinate is unknown (i.e., `0`).
+    pub const fn try_y_to_bool(self) -> Result<bool, BalanceError> {
+        match self.y() {
+            1 => Ok(true),
+            -1 => Ok(false),
+            _ => Err(BalanceError::Uncertain),
         }
     }
 
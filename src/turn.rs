@@ -0,0 +1,304 @@
+use crate::Balance;
+use core::ops::{Add, Neg};
+
+/// A rotation by a multiple of 45 degrees, relative to a heading.
+///
+/// The eight `Turn`s form the cyclic group of the rotations of the eight directions
+/// of a `Balance`: they can be composed ([Turn::compose] or `+`) and inverted
+/// ([Turn::inverse] or `-`). As with [Balance::rotate_left], turning left is
+/// turning counterclockwise in the 3x3 grid.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, Turn};
+///
+/// assert_eq!(Balance::Top.turn_to(Balance::Right), Some(Turn::Right));
+/// assert_eq!(Balance::Top.rotate_by(Turn::SlightLeft), Balance::TopLeft);
+/// assert_eq!(Turn::Left + Turn::SharpLeft, Turn::SharpRight);
+/// assert_eq!(-Turn::SlightLeft, Turn::SlightRight);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Turn {
+    /// No rotation.
+    Straight,
+    /// A 45 degrees counterclockwise rotation.
+    SlightLeft,
+    /// A 90 degrees counterclockwise rotation.
+    Left,
+    /// A 135 degrees counterclockwise rotation.
+    SharpLeft,
+    /// A 180 degrees rotation.
+    Back,
+    /// A 135 degrees clockwise rotation.
+    SharpRight,
+    /// A 90 degrees clockwise rotation.
+    Right,
+    /// A 45 degrees clockwise rotation.
+    SlightRight,
+}
+
+impl Turn {
+    /// All the turns, in counterclockwise order from `Turn::Straight`.
+    pub const ALL: [Turn; 8] = [
+        Turn::Straight,
+        Turn::SlightLeft,
+        Turn::Left,
+        Turn::SharpLeft,
+        Turn::Back,
+        Turn::SharpRight,
+        Turn::Right,
+        Turn::SlightRight,
+    ];
+
+    /// Constructs a `Turn` from a number of 45 degrees counterclockwise steps.
+    ///
+    /// Negative steps are clockwise, and the steps are taken modulo 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Turn;
+    ///
+    /// assert_eq!(Turn::from_steps(2), Turn::Left);
+    /// assert_eq!(Turn::from_steps(-1), Turn::SlightRight);
+    /// assert_eq!(Turn::from_steps(9), Turn::SlightLeft);
+    /// ```
+    pub const fn from_steps(steps: i8) -> Self {
+        Self::ALL[steps.rem_euclid(8) as usize]
+    }
+
+    /// Returns the number of 45 degrees counterclockwise steps of this `Turn`, in the range `0..8`.
+    pub const fn steps(self) -> u8 {
+        match self {
+            Turn::Straight => 0,
+            Turn::SlightLeft => 1,
+            Turn::Left => 2,
+            Turn::SharpLeft => 3,
+            Turn::Back => 4,
+            Turn::SharpRight => 5,
+            Turn::Right => 6,
+            Turn::SlightRight => 7,
+        }
+    }
+
+    /// Returns the shortest number of 45 degrees steps of this `Turn`, in the range `-3..=4`.
+    ///
+    /// Positive steps are counterclockwise and negative steps are clockwise.
+    /// `Turn::Back` is `4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Turn;
+    ///
+    /// assert_eq!(Turn::SharpLeft.signed_steps(), 3);
+    /// assert_eq!(Turn::SlightRight.signed_steps(), -1);
+    /// assert_eq!(Turn::Back.signed_steps(), 4);
+    /// ```
+    pub const fn signed_steps(self) -> i8 {
+        let steps = self.steps() as i8;
+        if steps > 4 {
+            steps - 8
+        } else {
+            steps
+        }
+    }
+
+    /// Returns the angle of this `Turn` in degrees, in the range `(-180.0, 180.0]`.
+    ///
+    /// Positive angles are counterclockwise.
+    pub const fn to_degrees(self) -> f64 {
+        self.signed_steps() as f64 * 45.0
+    }
+
+    /// Returns the `Turn` equivalent to this `Turn` followed by `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Turn;
+    ///
+    /// assert_eq!(Turn::Left.compose(Turn::Left), Turn::Back);
+    /// assert_eq!(Turn::SlightRight.compose(Turn::SlightLeft), Turn::Straight);
+    /// ```
+    pub const fn compose(self, other: Self) -> Self {
+        Self::from_steps((self.steps() + other.steps()) as i8)
+    }
+
+    /// Returns the `Turn` which cancels this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Turn;
+    ///
+    /// assert_eq!(Turn::SharpLeft.inverse(), Turn::SharpRight);
+    /// assert_eq!(Turn::Back.inverse(), Turn::Back);
+    /// ```
+    pub const fn inverse(self) -> Self {
+        Self::from_steps(-(self.steps() as i8))
+    }
+}
+
+impl Add for Turn {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl Neg for Turn {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl Balance {
+    /// Returns the index of the direction on the counterclockwise ring of the eight directions,
+    /// starting from `Balance::Right`, or `None` for `Balance::Center`.
+    const fn octant(self) -> Option<u8> {
+        match self {
+            Balance::Right => Some(0),
+            Balance::TopRight => Some(1),
+            Balance::Top => Some(2),
+            Balance::TopLeft => Some(3),
+            Balance::Left => Some(4),
+            Balance::BottomLeft => Some(5),
+            Balance::Bottom => Some(6),
+            Balance::BottomRight => Some(7),
+            Balance::Center => None,
+        }
+    }
+
+    /// Returns the direction at the given index of the counterclockwise ring of the eight directions.
+    const fn from_octant(octant: u8) -> Self {
+        match octant % 8 {
+            0 => Balance::Right,
+            1 => Balance::TopRight,
+            2 => Balance::Top,
+            3 => Balance::TopLeft,
+            4 => Balance::Left,
+            5 => Balance::BottomLeft,
+            6 => Balance::Bottom,
+            _ => Balance::BottomRight,
+        }
+    }
+
+    /// Rotates the current position by the given `Turn` around the center of the 3x3 grid.
+    ///
+    /// The center position (`Balance::Center`) remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Turn};
+    ///
+    /// assert_eq!(Balance::Right.rotate_by(Turn::Left), Balance::Top);
+    /// assert_eq!(Balance::Right.rotate_by(Turn::SharpRight), Balance::BottomLeft);
+    /// assert_eq!(Balance::Center.rotate_by(Turn::Back), Balance::Center);
+    /// ```
+    pub const fn rotate_by(self, turn: Turn) -> Self {
+        match self.octant() {
+            Some(octant) => Self::from_octant(octant + turn.steps()),
+            None => Balance::Center,
+        }
+    }
+
+    /// Returns the `Turn` which rotates the current direction onto `other`.
+    ///
+    /// # Returns
+    ///
+    /// The `Turn` such that `self.rotate_by(turn) == other`, or `None` if one of the
+    /// positions is `Balance::Center`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, Turn};
+    ///
+    /// assert_eq!(Balance::Top.turn_to(Balance::BottomLeft), Some(Turn::SharpLeft));
+    /// assert_eq!(Balance::Left.turn_to(Balance::Left), Some(Turn::Straight));
+    /// assert_eq!(Balance::Left.turn_to(Balance::Center), None);
+    /// ```
+    pub const fn turn_to(self, other: Self) -> Option<Turn> {
+        match (self.octant(), other.octant()) {
+            (Some(from), Some(to)) => Some(Turn::from_steps(to as i8 - from as i8)),
+            _ => None,
+        }
+    }
+
+    /// Rotates the current direction towards `target` by at most `max_steps` steps of 45 degrees.
+    ///
+    /// The rotation follows the shortest way; when `target` is exactly behind, the
+    /// rotation is counterclockwise.
+    ///
+    /// # Returns
+    ///
+    /// `target` if it can be reached within `max_steps` steps, the intermediate direction otherwise.
+    /// If `self` or `target` is `Balance::Center`, `self` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::Balance;
+    ///
+    /// assert_eq!(Balance::Top.turn_towards(Balance::Right, 1), Balance::TopRight);
+    /// assert_eq!(Balance::Top.turn_towards(Balance::Right, 3), Balance::Right);
+    /// assert_eq!(Balance::Top.turn_towards(Balance::Bottom, 2), Balance::Left);
+    /// assert_eq!(Balance::Top.turn_towards(Balance::Center, 2), Balance::Top);
+    /// ```
+    pub const fn turn_towards(self, target: Self, max_steps: u8) -> Self {
+        let steps = match self.turn_to(target) {
+            Some(turn) => turn.signed_steps(),
+            None => return self,
+        };
+        let max_steps = if max_steps > 4 { 4 } else { max_steps as i8 };
+        let steps = if steps > max_steps {
+            max_steps
+        } else if steps < -max_steps {
+            -max_steps
+        } else {
+            steps
+        };
+        self.rotate_by(Turn::from_steps(steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Balance, Turn};
+
+    #[test]
+    fn test_group_laws() {
+        for a in Turn::ALL {
+            assert_eq!(a + -a, Turn::Straight);
+            assert_eq!(a + Turn::Straight, a);
+            for b in Turn::ALL {
+                assert_eq!(a + b, b + a);
+                for c in Turn::ALL {
+                    assert_eq!((a + b) + c, a + (b + c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_turns_match_rotations() {
+        let ring = Turn::ALL.map(|turn| Balance::Right.rotate_by(turn));
+        for balance in ring {
+            assert_eq!(balance.rotate_by(Turn::SlightLeft), balance.rotate_ccw_45());
+            assert_eq!(balance.rotate_by(Turn::SlightRight), balance.rotate_cw_45());
+            assert_eq!(balance.rotate_by(Turn::Left), balance.rotate_left());
+            assert_eq!(balance.rotate_by(Turn::Right), balance.rotate_right());
+            assert_eq!(balance.rotate_by(Turn::Back), -balance);
+            for other in ring {
+                let turn = balance.turn_to(other).unwrap();
+                assert_eq!(balance.rotate_by(turn), other);
+            }
+        }
+    }
+}
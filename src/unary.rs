@@ -0,0 +1,407 @@
+use crate::Balance;
+
+/// Builds a `UnaryOp` by evaluating a (const) expression on each `Balance`.
+macro_rules! tabulate {
+    ($b:ident => $e:expr) => {
+        tabulate!(@ $b => $e; TopLeft, Top, TopRight, Left, Center, Right, BottomLeft, Bottom, BottomRight)
+    };
+    (@ $b:ident => $e:expr; $($variant:ident),*) => {
+        UnaryOp([$({
+            let $b = Balance::$variant;
+            $e
+        }),*])
+    };
+}
+
+/// A unary operation on `Balance`, stored as its table of nine results.
+///
+/// The table is indexed in the order of [Balance::to_value] (from `TopLeft` to `BottomRight`).
+/// Unlike a function `Balance -> Balance`, a `UnaryOp` can be stored, compared, composed,
+/// inverted and analysed exhaustively. Constants are provided for every unary method of `Balance`.
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{Balance, UnaryOp};
+///
+/// let op = UnaryOp::ROTATE_LEFT.compose(UnaryOp::FLIP_H);
+/// assert_eq!(op.apply(Balance::Top), Balance::Right);
+/// assert_eq!(op, UnaryOp::from_fn(|b| b.rotate_left().flip_h()));
+/// assert_eq!(UnaryOp::ROTATE_LEFT.power(4), UnaryOp::IDENTITY);
+/// assert_eq!(UnaryOp::ROTATE_LEFT.inverse(), Some(UnaryOp::ROTATE_RIGHT));
+/// assert_eq!(UnaryOp::UP.inverse(), None);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct UnaryOp(pub [Balance; 9]);
+
+impl UnaryOp {
+    /// Leaves every `Balance` unchanged.
+    pub const IDENTITY: Self = tabulate!(b => b);
+    /// See [Balance::up].
+    pub const UP: Self = tabulate!(b => b.up());
+    /// See [Balance::down].
+    pub const DOWN: Self = tabulate!(b => b.down());
+    /// See [Balance::left].
+    pub const LEFT: Self = tabulate!(b => b.left());
+    /// See [Balance::right].
+    pub const RIGHT: Self = tabulate!(b => b.right());
+    /// See [Balance::up_wrap].
+    pub const UP_WRAP: Self = tabulate!(b => b.up_wrap());
+    /// See [Balance::down_wrap].
+    pub const DOWN_WRAP: Self = tabulate!(b => b.down_wrap());
+    /// See [Balance::left_wrap].
+    pub const LEFT_WRAP: Self = tabulate!(b => b.left_wrap());
+    /// See [Balance::right_wrap].
+    pub const RIGHT_WRAP: Self = tabulate!(b => b.right_wrap());
+    /// See [Balance::flip_h].
+    pub const FLIP_H: Self = tabulate!(b => b.flip_h());
+    /// See [Balance::flip_v].
+    pub const FLIP_V: Self = tabulate!(b => b.flip_v());
+    /// See [Balance::rotate_left].
+    pub const ROTATE_LEFT: Self = tabulate!(b => b.rotate_left());
+    /// See [Balance::rotate_right].
+    pub const ROTATE_RIGHT: Self = tabulate!(b => b.rotate_right());
+    /// See [Balance::rotate_ccw_45].
+    pub const ROTATE_CCW_45: Self = tabulate!(b => b.rotate_ccw_45());
+    /// See [Balance::rotate_cw_45].
+    pub const ROTATE_CW_45: Self = tabulate!(b => b.rotate_cw_45());
+    /// See [Balance::center_h].
+    pub const CENTER_H: Self = tabulate!(b => b.center_h());
+    /// See [Balance::center_v].
+    pub const CENTER_V: Self = tabulate!(b => b.center_v());
+    /// See [Balance::not](core::ops::Not::not) (transpose).
+    pub const NOT: Self = tabulate!(b => Balance::from_vector(b.y(), b.x()));
+    /// See [Balance::neg](core::ops::Neg::neg).
+    pub const NEG: Self = tabulate!(b => Balance::from_vector(-b.x(), -b.y()));
+    /// See [Balance::possibly].
+    #[cfg(feature = "ternary")]
+    pub const POSSIBLY: Self = tabulate!(b => b.possibly());
+    /// See [Balance::necessary].
+    #[cfg(feature = "ternary")]
+    pub const NECESSARY: Self = tabulate!(b => b.necessary());
+    /// See [Balance::contingently].
+    #[cfg(feature = "ternary")]
+    pub const CONTINGENTLY: Self = tabulate!(b => b.contingently());
+    /// See [Balance::absolute_positive].
+    #[cfg(feature = "ternary")]
+    pub const ABSOLUTE_POSITIVE: Self = tabulate!(b => b.absolute_positive());
+    /// See [Balance::positive].
+    #[cfg(feature = "ternary")]
+    pub const POSITIVE: Self = tabulate!(b => b.positive());
+    /// See [Balance::not_negative].
+    #[cfg(feature = "ternary")]
+    pub const NOT_NEGATIVE: Self = tabulate!(b => b.not_negative());
+    /// See [Balance::not_positive].
+    #[cfg(feature = "ternary")]
+    pub const NOT_POSITIVE: Self = tabulate!(b => b.not_positive());
+    /// See [Balance::negative].
+    #[cfg(feature = "ternary")]
+    pub const NEGATIVE: Self = tabulate!(b => b.negative());
+    /// See [Balance::absolute_negative].
+    #[cfg(feature = "ternary")]
+    pub const ABSOLUTE_NEGATIVE: Self = tabulate!(b => b.absolute_negative());
+    /// See [Balance::ht_not].
+    #[cfg(feature = "ternary")]
+    pub const HT_NOT: Self = tabulate!(b => b.ht_not());
+    /// See [Balance::post].
+    #[cfg(feature = "ternary")]
+    pub const POST: Self = tabulate!(b => b.post());
+    /// See [Balance::pre].
+    #[cfg(feature = "ternary")]
+    pub const PRE: Self = tabulate!(b => b.pre());
+
+    /// Creates a `UnaryOp` by tabulating the function `f` on the nine `Balance` variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// let op = UnaryOp::from_fn(|b| -b);
+    /// assert_eq!(op, UnaryOp::NEG);
+    /// ```
+    pub fn from_fn(f: impl Fn(Balance) -> Balance) -> Self {
+        Self(Balance::ALL.map(f))
+    }
+
+    /// Applies the operation on a `Balance`.
+    pub const fn apply(&self, balance: Balance) -> Balance {
+        self.0[balance.index()]
+    }
+
+    /// Returns the `UnaryOp` equivalent to this operation followed by `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// let op = UnaryOp::UP.compose(UnaryOp::RIGHT);
+    /// assert_eq!(op.apply(Balance::Center), Balance::TopRight);
+    /// ```
+    pub const fn compose(self, other: Self) -> Self {
+        let mut table = self.0;
+        let mut i = 0;
+        while i < 9 {
+            table[i] = other.apply(table[i]);
+            i += 1;
+        }
+        Self(table)
+    }
+
+    /// Returns the operation applied `n` times (`IDENTITY` for `n == 0`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::UnaryOp;
+    ///
+    /// assert_eq!(UnaryOp::ROTATE_CCW_45.power(2), UnaryOp::ROTATE_LEFT);
+    /// assert_eq!(UnaryOp::ROTATE_CCW_45.power(8), UnaryOp::IDENTITY);
+    /// assert_eq!(UnaryOp::UP.power(5), UnaryOp::UP.power(2));
+    /// ```
+    pub const fn power(self, n: u32) -> Self {
+        let mut result = Self::IDENTITY;
+        let mut base = self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.compose(base);
+            }
+            base = base.compose(base);
+            n >>= 1;
+        }
+        result
+    }
+
+    /// Checks if the operation is a bijection (a permutation of the nine `Balance` variants).
+    pub const fn is_bijective(&self) -> bool {
+        self.inverse().is_some()
+    }
+
+    /// Returns the inverse operation, if the operation is bijective.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::UnaryOp;
+    ///
+    /// assert_eq!(UnaryOp::ROTATE_CW_45.inverse(), Some(UnaryOp::ROTATE_CCW_45));
+    /// assert_eq!(UnaryOp::CENTER_H.inverse(), None);
+    /// ```
+    pub const fn inverse(&self) -> Option<Self> {
+        let mut table = [None; 9];
+        let mut i = 0;
+        while i < 9 {
+            let image = self.0[i].index();
+            if table[image].is_some() {
+                return None;
+            }
+            table[image] = Some(Balance::ALL[i]);
+            i += 1;
+        }
+        let mut result = [Balance::Center; 9];
+        let mut i = 0;
+        while i < 9 {
+            result[i] = match table[i] {
+                Some(balance) => balance,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(Self(result))
+    }
+
+    /// Returns the order of a bijective operation: the smallest `n > 0` such that
+    /// `self.power(n) == IDENTITY`, or `None` if the operation is not bijective.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::UnaryOp;
+    ///
+    /// assert_eq!(UnaryOp::ROTATE_LEFT.order(), Some(4));
+    /// assert_eq!(UnaryOp::FLIP_H.order(), Some(2));
+    /// assert_eq!(UnaryOp::UP.order(), None);
+    /// ```
+    pub fn order(&self) -> Option<u32> {
+        if !self.is_bijective() {
+            return None;
+        }
+        let mut n = 1;
+        let mut current = *self;
+        while current != Self::IDENTITY {
+            current = current.compose(*self);
+            n += 1;
+        }
+        Some(n)
+    }
+
+    /// Returns an iterator over the `Balance` variants left unchanged by the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// let fixed: Vec<Balance> = UnaryOp::NOT.fixed_points().collect();
+    /// assert_eq!(fixed, vec![Balance::TopLeft, Balance::Center, Balance::BottomRight]);
+    /// ```
+    pub fn fixed_points(&self) -> impl Iterator<Item = Balance> + '_ {
+        Balance::ALL
+            .into_iter()
+            .filter(move |&balance| self.apply(balance) == balance)
+    }
+
+    /// Returns an iterator over the orbit of `start`: `start`, its image, the image of
+    /// its image... until a `Balance` is repeated. Each `Balance` is yielded once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// let orbit: Vec<Balance> = UnaryOp::ROTATE_LEFT.orbit(Balance::Top).collect();
+    /// assert_eq!(orbit, vec![Balance::Top, Balance::Left, Balance::Bottom, Balance::Right]);
+    ///
+    /// let orbit: Vec<Balance> = UnaryOp::UP.orbit(Balance::Bottom).collect();
+    /// assert_eq!(orbit, vec![Balance::Bottom, Balance::Center, Balance::Top]);
+    /// ```
+    pub fn orbit(&self, start: Balance) -> impl Iterator<Item = Balance> + '_ {
+        let mut seen = 0u16;
+        let mut next = Some(start);
+        core::iter::from_fn(move || {
+            let current = next?;
+            seen |= 1 << current.index();
+            let image = self.apply(current);
+            next = if seen & (1 << image.index()) == 0 {
+                Some(image)
+            } else {
+                None
+            };
+            Some(current)
+        })
+    }
+
+    /// Returns the length of the cycle containing `balance`, or `None` if `balance`
+    /// is never reached again by applying the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// assert_eq!(UnaryOp::ROTATE_CCW_45.period(Balance::Top), Some(8));
+    /// assert_eq!(UnaryOp::ROTATE_CCW_45.period(Balance::Center), Some(1));
+    /// assert_eq!(UnaryOp::UP.period(Balance::Bottom), None);
+    /// ```
+    pub const fn period(&self, balance: Balance) -> Option<u32> {
+        let mut current = self.apply(balance);
+        let mut n = 1;
+        while n <= 9 {
+            if current.index() == balance.index() {
+                return Some(n);
+            }
+            current = self.apply(current);
+            n += 1;
+        }
+        None
+    }
+
+    /// Returns an iterator over the cycles of the operation.
+    ///
+    /// Each cycle is an iterator over its `Balance` variants, starting from the first one
+    /// in the order of [Balance::to_value]. Fixed points are cycles of length one. For a
+    /// bijective operation, this is the cycle decomposition of the permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, UnaryOp};
+    ///
+    /// let cycles: Vec<Vec<Balance>> = UnaryOp::FLIP_H.cycles().map(Iterator::collect).collect();
+    /// assert_eq!(cycles, vec![
+    ///     vec![Balance::TopLeft, Balance::TopRight],
+    ///     vec![Balance::Top],
+    ///     vec![Balance::Left, Balance::Right],
+    ///     vec![Balance::Center],
+    ///     vec![Balance::BottomLeft, Balance::BottomRight],
+    ///     vec![Balance::Bottom],
+    /// ]);
+    ///
+    /// let cycles: Vec<Vec<Balance>> = UnaryOp::UP.cycles().map(Iterator::collect).collect();
+    /// assert_eq!(cycles, vec![vec![Balance::TopLeft], vec![Balance::Top], vec![Balance::TopRight]]);
+    /// ```
+    pub fn cycles(&self) -> impl Iterator<Item = impl Iterator<Item = Balance> + '_> + '_ {
+        Balance::ALL
+            .into_iter()
+            .filter(move |&balance| {
+                self.period(balance).is_some()
+                    && self
+                        .orbit(balance)
+                        .all(|other| other.index() >= balance.index())
+            })
+            .map(move |balance| self.orbit(balance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        let pairs = [
+            (UnaryOp::IDENTITY, UnaryOp::from_fn(|b| b)),
+            (UnaryOp::UP, UnaryOp::from_fn(Balance::up)),
+            (UnaryOp::DOWN, UnaryOp::from_fn(Balance::down)),
+            (UnaryOp::LEFT, UnaryOp::from_fn(Balance::left)),
+            (UnaryOp::RIGHT, UnaryOp::from_fn(Balance::right)),
+            (UnaryOp::UP_WRAP, UnaryOp::from_fn(Balance::up_wrap)),
+            (UnaryOp::DOWN_WRAP, UnaryOp::from_fn(Balance::down_wrap)),
+            (UnaryOp::LEFT_WRAP, UnaryOp::from_fn(Balance::left_wrap)),
+            (UnaryOp::RIGHT_WRAP, UnaryOp::from_fn(Balance::right_wrap)),
+            (UnaryOp::FLIP_H, UnaryOp::from_fn(Balance::flip_h)),
+            (UnaryOp::FLIP_V, UnaryOp::from_fn(Balance::flip_v)),
+            (UnaryOp::ROTATE_LEFT, UnaryOp::from_fn(Balance::rotate_left)),
+            (
+                UnaryOp::ROTATE_RIGHT,
+                UnaryOp::from_fn(Balance::rotate_right),
+            ),
+            (
+                UnaryOp::ROTATE_CCW_45,
+                UnaryOp::from_fn(Balance::rotate_ccw_45),
+            ),
+            (
+                UnaryOp::ROTATE_CW_45,
+                UnaryOp::from_fn(Balance::rotate_cw_45),
+            ),
+            (UnaryOp::CENTER_H, UnaryOp::from_fn(Balance::center_h)),
+            (UnaryOp::CENTER_V, UnaryOp::from_fn(Balance::center_v)),
+            (UnaryOp::NOT, UnaryOp::from_fn(|b| !b)),
+            (UnaryOp::NEG, UnaryOp::from_fn(|b| -b)),
+        ];
+        for (op, expected) in pairs {
+            assert_eq!(op, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ternary")]
+    fn test_inverse() {
+        let bijections = [
+            UnaryOp::IDENTITY,
+            UnaryOp::UP_WRAP,
+            UnaryOp::ROTATE_CCW_45,
+            UnaryOp::NOT,
+            UnaryOp::POST,
+            UnaryOp::PRE,
+        ];
+        for op in bijections {
+            let inverse = op.inverse().unwrap();
+            assert_eq!(op.compose(inverse), UnaryOp::IDENTITY);
+            assert_eq!(inverse.compose(op), UnaryOp::IDENTITY);
+        }
+        assert_eq!(UnaryOp::POST.inverse(), Some(UnaryOp::PRE));
+    }
+}
@@ -0,0 +1,286 @@
+#[cfg(feature = "alloc")]
+use crate::Path;
+use crate::{ArrayPath, Balance, BalanceError, PathSlice, Steps, ZipWith};
+use core::iter::{Copied, FusedIterator};
+use core::slice::Iter;
+
+/// The movements of a `PathSlice`, by value.
+type SliceSteps<'a> = Copied<Iter<'a, Balance>>;
+
+/// Selects how two paths of different lengths are combined by
+/// [Path::each_zip_with_policy](crate::Path::each_zip_with_policy).
+///
+/// Every policy but [ZipPolicy::Truncate] extends the shorter path to the length of the
+/// longer one, so that no movement is silently dropped. As the movements are combined by
+/// any `Fn(Balance, Balance) -> Balance`, the policies apply to every binary operation
+/// (`Balance::add`, `Balance::bitand`, a [BinaryOp](crate::BinaryOp)...).
+///
+/// # Examples
+///
+/// ```
+/// use balanced_direction::{ArrayPath, Balance, ZipPolicy};
+/// use core::ops::Add;
+///
+/// let long = ArrayPath::<3>::from_slice(&[Balance::Top, Balance::Top, Balance::Top]).unwrap();
+/// let short = ArrayPath::<3>::from_slice(&[Balance::Right, Balance::Left]).unwrap();
+///
+/// let zip = |policy| long.each_zip_with_policy(Balance::add, &short, policy);
+/// assert_eq!(zip(ZipPolicy::Truncate).len(), 2);
+/// assert_eq!(zip(ZipPolicy::PadCenter)[2], Balance::Top);
+/// assert_eq!(zip(ZipPolicy::Pad(Balance::Bottom))[2], Balance::Center);
+/// assert_eq!(zip(ZipPolicy::Cycle)[2], Balance::TopRight);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum ZipPolicy {
+    /// Stops at the end of the shorter path (the behaviour of `Path::each_zip`).
+    #[default]
+    Truncate,
+    /// Extends the shorter path with `Balance::Center` movements.
+    PadCenter,
+    /// Extends the shorter path with the given movement.
+    Pad(Balance),
+    /// Repeats the movements of the shorter path from its start. An empty path cannot be
+    /// repeated: zipping with it gives an empty result, as with [ZipPolicy::Truncate].
+    Cycle,
+}
+
+impl<'a> PathSlice<'a> {
+    /// Returns the results of `f` on the pairs of movements of this `PathSlice` and `other`,
+    /// which must have the same length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::LengthMismatch] if the lengths differ.
+    pub fn try_each_zip<'b, F: Fn(Balance, Balance) -> Balance>(
+        &self,
+        f: F,
+        other: PathSlice<'b>,
+    ) -> Result<ZipWith<SliceSteps<'a>, SliceSteps<'b>, F>, BalanceError> {
+        if self.len() != other.len() {
+            return Err(BalanceError::LengthMismatch(self.len(), other.len()));
+        }
+        Ok(self.steps().zip_with(f, other.steps()))
+    }
+
+    /// Returns the results of `f` on the pairs of movements of this `PathSlice` and `other`,
+    /// the shorter one being extended according to `policy`.
+    pub fn each_zip_with_policy<'b, F: Fn(Balance, Balance) -> Balance>(
+        &self,
+        f: F,
+        other: PathSlice<'b>,
+        policy: ZipPolicy,
+    ) -> PolicyZip<'a, 'b, F> {
+        let len = match policy {
+            ZipPolicy::Truncate => self.len().min(other.len()),
+            ZipPolicy::Cycle if self.is_empty() || other.is_empty() => 0,
+            _ => self.len().max(other.len()),
+        };
+        PolicyZip {
+            a: self.as_slice(),
+            b: other.as_slice(),
+            f,
+            policy,
+            index: 0,
+            len,
+        }
+    }
+}
+
+/// An iterator applying a function to the pairs of movements of two paths, the shorter
+/// one being extended according to a [ZipPolicy].
+///
+/// This `struct` is created by [PathSlice::each_zip_with_policy].
+#[derive(Debug, Clone)]
+pub struct PolicyZip<'a, 'b, F> {
+    a: &'a [Balance],
+    b: &'b [Balance],
+    f: F,
+    policy: ZipPolicy,
+    index: usize,
+    len: usize,
+}
+
+impl<F> PolicyZip<'_, '_, F> {
+    /// Returns the movement of `movements` paired with the movement at `index`.
+    fn movement(&self, movements: &[Balance], index: usize) -> Balance {
+        match (movements.get(index), self.policy) {
+            (Some(&movement), _) => movement,
+            (None, ZipPolicy::Pad(padding)) => padding,
+            (None, ZipPolicy::Cycle) => movements[index % movements.len()],
+            (None, _) => Balance::Center,
+        }
+    }
+}
+
+impl<F: Fn(Balance, Balance) -> Balance> Iterator for PolicyZip<'_, '_, F> {
+    type Item = Balance;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.len {
+            return None;
+        }
+        let a = self.movement(self.a, self.index);
+        let b = self.movement(self.b, self.index);
+        self.index += 1;
+        Some((self.f)(a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<F: Fn(Balance, Balance) -> Balance> ExactSizeIterator for PolicyZip<'_, '_, F> {}
+
+impl<F: Fn(Balance, Balance) -> Balance> FusedIterator for PolicyZip<'_, '_, F> {}
+
+#[cfg(feature = "alloc")]
+impl Path {
+    /// Applies `f` to the pairs of movements of this `Path` and `other`, which must have the
+    /// same length: the strict counterpart of [Path::each_zip].
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::LengthMismatch] with both lengths if they differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BalanceError, Path};
+    /// use core::ops::Add;
+    ///
+    /// let recorded = Path::new(vec![Balance::Top, Balance::Right]);
+    /// let replayed = Path::new(vec![Balance::Top]);
+    /// assert_eq!(
+    ///     recorded.try_each_zip(Balance::add, &replayed),
+    ///     Err(BalanceError::LengthMismatch(2, 1))
+    /// );
+    /// assert!(recorded.try_each_zip(Balance::add, &recorded).is_ok());
+    /// ```
+    pub fn try_each_zip(
+        &self,
+        f: impl Fn(Balance, Balance) -> Balance,
+        other: &Self,
+    ) -> Result<Self, BalanceError> {
+        let zipped = self
+            .as_path_slice()
+            .try_each_zip(f, other.as_path_slice())?;
+        Ok(zipped.collect_path())
+    }
+
+    /// Applies `f` to the pairs of movements of this `Path` and `other`, the shorter one
+    /// being extended according to `policy` (see [ZipPolicy]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use balanced_direction::{Balance, BinaryOp, Path, ZipPolicy};
+    ///
+    /// let states = Path::new(vec![Balance::BottomRight, Balance::Right, Balance::Top]);
+    /// let mask = Path::new(vec![Balance::Bottom]);
+    /// let masked = states.each_zip_with_policy(
+    ///     |a, b| BinaryOp::MUL.apply(a, b),
+    ///     &mask,
+    ///     ZipPolicy::Cycle,
+    /// );
+    /// assert_eq!(masked, Path::new(vec![Balance::Bottom, Balance::Center, Balance::Top]));
+    /// ```
+    pub fn each_zip_with_policy(
+        &self,
+        f: impl Fn(Balance, Balance) -> Balance,
+        other: &Self,
+        policy: ZipPolicy,
+    ) -> Self {
+        self.as_path_slice()
+            .each_zip_with_policy(f, other.as_path_slice(), policy)
+            .collect_path()
+    }
+}
+
+impl<const N: usize> ArrayPath<N> {
+    /// Applies `f` to the pairs of movements of this `ArrayPath` and `other`, which must have
+    /// the same length (see `Path::try_each_zip`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [BalanceError::LengthMismatch] with both lengths if they differ.
+    pub fn try_each_zip(
+        &self,
+        f: impl Fn(Balance, Balance) -> Balance,
+        other: &Self,
+    ) -> Result<Self, BalanceError> {
+        let zipped = self
+            .as_path_slice()
+            .try_each_zip(f, other.as_path_slice())?;
+        Ok(zipped.collect())
+    }
+
+    /// Applies `f` to the pairs of movements of this `ArrayPath` and `other`, the shorter one
+    /// being extended according to `policy` (see `Path::each_zip_with_policy`).
+    pub fn each_zip_with_policy(
+        &self,
+        f: impl Fn(Balance, Balance) -> Balance,
+        other: &Self,
+        policy: ZipPolicy,
+    ) -> Self {
+        self.as_path_slice()
+            .each_zip_with_policy(f, other.as_path_slice(), policy)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArrayPath, Balance, BalanceError, ZipPolicy};
+
+    #[test]
+    fn test_policies() {
+        let long = ArrayPath::<5>::from_slice(&[Balance::Top; 5]).unwrap();
+        let short = ArrayPath::<5>::from_slice(&[Balance::Left, Balance::Right]).unwrap();
+        let empty = ArrayPath::<5>::new();
+        let first = |a: Balance, _: Balance| a;
+        let second = |_: Balance, b: Balance| b;
+
+        let zip =
+            |a: &ArrayPath<5>, b: &ArrayPath<5>, policy| a.each_zip_with_policy(second, b, policy);
+        assert_eq!(zip(&long, &short, ZipPolicy::Truncate)[..], short[..]);
+        assert_eq!(
+            zip(&long, &short, ZipPolicy::PadCenter)[..],
+            [
+                Balance::Left,
+                Balance::Right,
+                Balance::Center,
+                Balance::Center,
+                Balance::Center
+            ]
+        );
+        assert_eq!(
+            zip(&long, &short, ZipPolicy::Pad(Balance::Bottom))[2..],
+            [Balance::Bottom; 3]
+        );
+        assert_eq!(
+            zip(&long, &short, ZipPolicy::Cycle)[..],
+            [
+                Balance::Left,
+                Balance::Right,
+                Balance::Left,
+                Balance::Right,
+                Balance::Left
+            ]
+        );
+        assert!(zip(&long, &empty, ZipPolicy::Cycle).is_empty());
+        assert_eq!(zip(&long, &empty, ZipPolicy::PadCenter).len(), 5);
+        // The policy extends whichever path is shorter.
+        assert_eq!(
+            short.each_zip_with_policy(first, &long, ZipPolicy::Cycle),
+            zip(&long, &short, ZipPolicy::Cycle)
+        );
+
+        assert_eq!(
+            long.try_each_zip(first, &short),
+            Err(BalanceError::LengthMismatch(5, 2))
+        );
+        assert_eq!(long.try_each_zip(second, &long), Ok(long));
+    }
+}